@@ -4,6 +4,7 @@
 //
 use crate::float::Float;
 use crate::point::Pt;
+use crate::transform::Transform;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
@@ -186,6 +187,21 @@ where
         bbox
     }
 
+    /// Create a new bounding box from a center point and size
+    ///
+    /// * `center` Center point.
+    /// * `width` X span.
+    /// * `height` Y span.
+    pub fn from_center_size<P>(center: P, width: F, height: F) -> Self
+    where
+        P: Into<Pt<F>>,
+    {
+        let two = F::one() + F::one();
+        let center = center.into();
+        let half = Pt::new(width / two, height / two);
+        Self::new([center - half, center + half])
+    }
+
     /// Extend bounding box with a set of points
     pub fn extend<I, P>(&mut self, pts: I)
     where
@@ -195,6 +211,17 @@ where
         pts.into_iter().for_each(|p| self.include_pt(p));
     }
 
+    /// Extend bounding box to include another shape
+    pub fn include<B>(&mut self, shape: B)
+    where
+        B: Into<Self>,
+    {
+        let rhs = shape.into();
+        let minp = self.pts[0].with_min(rhs.pts[0]);
+        let maxp = self.pts[1].with_max(rhs.pts[1]);
+        self.pts = [minp, maxp];
+    }
+
     fn include_pt<P>(&mut self, p: P)
     where
         P: Into<Pt<F>>,
@@ -245,6 +272,234 @@ where
         self.y_max() - self.y_min()
     }
 
+    /// Get the axis with the longest span (0 for X, 1 for Y).
+    ///
+    /// If both spans are equal, X is chosen.
+    pub fn longest_axis(self) -> usize {
+        Pt::new(self.x_span(), self.y_span()).longest_axis()
+    }
+
+    /// Split at the middle of the longest axis.
+    pub fn split_longest(self) -> (Self, Self) {
+        let [minp, maxp] = self.pts;
+        if self.longest_axis() == 0 {
+            let xmd = self.x_mid();
+            (
+                Self {
+                    pts: [minp, Pt::new(xmd, maxp.y)],
+                },
+                Self {
+                    pts: [Pt::new(xmd, minp.y), maxp],
+                },
+            )
+        } else {
+            let ymd = self.y_mid();
+            (
+                Self {
+                    pts: [minp, Pt::new(maxp.x, ymd)],
+                },
+                Self {
+                    pts: [Pt::new(minp.x, ymd), maxp],
+                },
+            )
+        }
+    }
+
+    /// Get the area of overlap with another box.
+    ///
+    /// Returns zero if the boxes are disjoint.
+    pub fn overlap_area(self, rhs: Self) -> F {
+        let minp = self.pts[0].with_max(rhs.pts[0]);
+        let maxp = self.pts[1].with_min(rhs.pts[1]);
+        let w = (maxp.x - minp.x).max(F::zero());
+        let h = (maxp.y - minp.y).max(F::zero());
+        w * h
+    }
+
+    /// Inset by a fraction of the box size.
+    ///
+    /// Each side is moved inward by `fx * x_span` and `fy * y_span`.  A
+    /// fraction of 0.5 collapses the box to its center line.
+    pub fn inset_fraction(self, fx: F, fy: F) -> Self {
+        let dx = fx * self.x_span();
+        let dy = fy * self.y_span();
+        let minp = self.pts[0] + (dx, dy);
+        let maxp = self.pts[1] - (dx, dy);
+        Self { pts: [minp, maxp] }
+    }
+
+    /// Pad to an aspect ratio, keeping the same center.
+    ///
+    /// The shorter dimension is expanded (never shrunk) so that
+    /// `x_span / y_span` equals `ratio`.
+    pub fn pad_to_aspect(self, ratio: F) -> Self {
+        let two = F::one() + F::one();
+        let (xs, ys) = (self.x_span(), self.y_span());
+        let (dx, dy) = if xs < ys * ratio {
+            ((ys * ratio - xs) / two, F::zero())
+        } else {
+            (F::zero(), (xs / ratio - ys) / two)
+        };
+        let minp = self.pts[0] - (dx, dy);
+        let maxp = self.pts[1] + (dx, dy);
+        Self { pts: [minp, maxp] }
+    }
+
+    /// Expand to a square, keeping the same center.
+    ///
+    /// The shorter dimension is expanded to match the longer one.
+    pub fn to_square(self) -> Self {
+        self.pad_to_aspect(F::one())
+    }
+
+    /// Clamp within a container box.
+    ///
+    /// The box is moved (not resized) the minimum amount needed to fit
+    /// inside `container`.  On any axis where it is larger than the
+    /// container, it is centered instead.
+    pub fn clamp_within(self, container: Self) -> Self {
+        let shift = |mn: F, mx: F, cmn: F, cmx: F| {
+            if mx - mn > cmx - cmn {
+                (cmn + cmx - mn - mx) / (F::one() + F::one())
+            } else if mn < cmn {
+                cmn - mn
+            } else if mx > cmx {
+                cmx - mx
+            } else {
+                F::zero()
+            }
+        };
+        let dx = shift(
+            self.x_min(),
+            self.x_max(),
+            container.x_min(),
+            container.x_max(),
+        );
+        let dy = shift(
+            self.y_min(),
+            self.y_max(),
+            container.y_min(),
+            container.y_max(),
+        );
+        let minp = self.pts[0] + (dx, dy);
+        let maxp = self.pts[1] + (dx, dy);
+        Self { pts: [minp, maxp] }
+    }
+
+    /// Get the corners in clockwise order.
+    ///
+    /// Starts at the minimum corner, then (`x_min`, `y_max`), maximum
+    /// corner and (`x_max`, `y_min`).
+    pub fn corners_cw(self) -> [Pt<F>; 4] {
+        let [minp, maxp] = self.pts;
+        [minp, Pt::new(minp.x, maxp.y), maxp, Pt::new(maxp.x, minp.y)]
+    }
+
+    /// Get the corners in counter-clockwise order.
+    ///
+    /// Starts at the minimum corner, then (`x_max`, `y_min`), maximum
+    /// corner and (`x_min`, `y_max`).
+    pub fn corners_ccw(self) -> [Pt<F>; 4] {
+        let [minp, maxp] = self.pts;
+        [minp, Pt::new(maxp.x, minp.y), maxp, Pt::new(minp.x, maxp.y)]
+    }
+
+    /// Round bounds outward to multiples of a step.
+    ///
+    /// The result always contains the original box.
+    pub fn round_out(self, step: F) -> Self {
+        let minp = Pt::new(
+            (self.x_min() / step).floor() * step,
+            (self.y_min() / step).floor() * step,
+        );
+        let maxp = Pt::new(
+            (self.x_max() / step).ceil() * step,
+            (self.y_max() / step).ceil() * step,
+        );
+        Self { pts: [minp, maxp] }
+    }
+
+    /// Round bounds inward to multiples of a step.
+    ///
+    /// The result is always contained by the original box.  If no grid
+    /// lines are within the box on an axis, the result has no valid bounds.
+    pub fn round_in(self, step: F) -> Self {
+        let minp = Pt::new(
+            (self.x_min() / step).ceil() * step,
+            (self.y_min() / step).ceil() * step,
+        );
+        let maxp = Pt::new(
+            (self.x_max() / step).floor() * step,
+            (self.y_max() / step).floor() * step,
+        );
+        Self { pts: [minp, maxp] }
+    }
+
+    /// Get an iterator of evenly spaced lattice points.
+    ///
+    /// The points span from minimum to maximum (inclusive) on each axis,
+    /// in row-major order.  If only one point is requested on an axis, it
+    /// is placed at the minimum.
+    ///
+    /// * `nx` Number of points along X axis.
+    /// * `ny` Number of points along Y axis.
+    pub fn lattice(self, nx: usize, ny: usize) -> impl Iterator<Item = Pt<F>> {
+        let step = |span: F, n: usize| {
+            if n > 1 {
+                span / F::from(n - 1).unwrap()
+            } else {
+                F::zero()
+            }
+        };
+        let dx = step(self.x_span(), nx);
+        let dy = step(self.y_span(), ny);
+        let (xmn, ymn) = (self.x_min(), self.y_min());
+        (0..ny).flat_map(move |j| {
+            let y = ymn + dy * F::from(j).unwrap();
+            (0..nx).map(move |i| Pt::new(xmn + dx * F::from(i).unwrap(), y))
+        })
+    }
+
+    /// Get an iterator of cell center points.
+    ///
+    /// The box is divided into a grid of equal cells, and the center of
+    /// each cell is yielded in row-major order.
+    ///
+    /// * `cols` Number of cells along X axis.
+    /// * `rows` Number of cells along Y axis.
+    pub fn cell_centers(
+        self,
+        cols: usize,
+        rows: usize,
+    ) -> impl Iterator<Item = Pt<F>> {
+        let half = F::one() / (F::one() + F::one());
+        let dx = self.x_span() / F::from(cols.max(1)).unwrap();
+        let dy = self.y_span() / F::from(rows.max(1)).unwrap();
+        let (xmn, ymn) = (self.x_min(), self.y_min());
+        (0..rows).flat_map(move |j| {
+            let y = ymn + dy * (F::from(j).unwrap() + half);
+            (0..cols).map(move |i| {
+                Pt::new(xmn + dx * (F::from(i).unwrap() + half), y)
+            })
+        })
+    }
+
+    /// Get a transform mapping the bounding box onto the unit square.
+    ///
+    /// The minimum corner maps to (0, 0) and the maximum to (1, 1).  An
+    /// axis with zero span is translated but not scaled.
+    pub fn to_unit_transform(self) -> Transform<F> {
+        let scale = |span: F| {
+            if span > F::zero() {
+                F::one() / span
+            } else {
+                F::one()
+            }
+        };
+        Transform::with_translate(-self.x_min(), -self.y_min())
+            .scale(scale(self.x_span()), scale(self.y_span()))
+    }
+
     /// Check bounds
     pub fn check(self, x: F, y: F) -> Bounds {
         let x = if x < self.x_min() {
@@ -306,6 +561,16 @@ mod test {
         assert_eq!(b.y_span(), 190.0);
     }
 
+    #[test]
+    fn from_center_size() {
+        let b = BBox::from_center_size((5.0, -2.0), 4.0, 3.0);
+        assert_eq!(b, BBox::new([(3.0, -3.5), (7.0, -0.5)]));
+        assert_eq!(b.x_mid(), 5.0);
+        assert_eq!(b.y_mid(), -2.0);
+        assert_eq!(b.x_span(), 4.0);
+        assert_eq!(b.y_span(), 3.0);
+    }
+
     #[test]
     fn from_vec() {
         let pts = [
@@ -346,6 +611,178 @@ mod test {
         assert!(!p.bounded_by(BBox::new([(0.0, 0.0), (1.0, 1.0)])));
     }
 
+    #[test]
+    fn include() {
+        let mut a = BBox::new([(0.0, 0.0), (1.0, 1.0)]);
+        a.include(BBox::new([(0.5, -1.0), (2.0, 0.5)]));
+        assert_eq!(a, BBox::new([(0.0, -1.0), (2.0, 1.0)]));
+        a.include(Pt::new(-3.0, 0.0));
+        assert_eq!(a, BBox::new([(-3.0, -1.0), (2.0, 1.0)]));
+        a.include(BBox::default());
+        assert_eq!(a, BBox::new([(-3.0, -1.0), (2.0, 1.0)]));
+        let mut b = BBox::default();
+        b.include(a);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn lattice() {
+        let b = BBox::new([(0.0, 0.0), (10.0, 4.0)]);
+        let pts: Vec<_> = b.lattice(6, 3).collect();
+        assert_eq!(pts.len(), 18);
+        assert_eq!(pts[0], Pt::new(0.0, 0.0));
+        assert_eq!(pts[1], Pt::new(2.0, 0.0));
+        assert_eq!(pts[6], Pt::new(0.0, 2.0));
+        assert_eq!(pts[17], Pt::new(10.0, 4.0));
+        let pts: Vec<_> = b.lattice(1, 1).collect();
+        assert_eq!(pts, [Pt::new(0.0, 0.0)]);
+        assert_eq!(b.lattice(0, 5).count(), 0);
+    }
+
+    #[test]
+    fn to_unit_transform() {
+        let b = BBox::new([(2.0, -1.0), (6.0, 1.0)]);
+        let t = b.to_unit_transform();
+        assert_eq!(t * Pt::new(2.0, -1.0), Pt::new(0.0, 0.0));
+        assert_eq!(t * Pt::new(6.0, -1.0), Pt::new(1.0, 0.0));
+        assert_eq!(t * Pt::new(6.0, 1.0), Pt::new(1.0, 1.0));
+        assert_eq!(t * Pt::new(2.0, 1.0), Pt::new(0.0, 1.0));
+        let b = BBox::new([(3.0, 1.0), (3.0, 5.0)]);
+        let t = b.to_unit_transform();
+        assert_eq!(t * Pt::new(3.0, 5.0), Pt::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn cell_centers() {
+        let b = BBox::new([(0.0, 0.0), (8.0, 3.0)]);
+        let pts: Vec<_> = b.cell_centers(4, 3).collect();
+        assert_eq!(pts.len(), 12);
+        assert_eq!(pts[0], Pt::new(1.0, 0.5));
+        assert_eq!(pts[1], Pt::new(3.0, 0.5));
+        assert_eq!(pts[4], Pt::new(1.0, 1.5));
+        assert_eq!(pts[11], Pt::new(7.0, 2.5));
+        let pts: Vec<_> = b.cell_centers(1, 1).collect();
+        assert_eq!(pts, [Pt::new(4.0, 1.5)]);
+        assert_eq!(b.cell_centers(0, 5).count(), 0);
+    }
+
+    #[test]
+    fn split_longest() {
+        let a = BBox::new([(0.0, 0.0), (4.0, 2.0)]);
+        assert_eq!(a.longest_axis(), 0);
+        assert_eq!(
+            a.split_longest(),
+            (
+                BBox::new([(0.0, 0.0), (2.0, 2.0)]),
+                BBox::new([(2.0, 0.0), (4.0, 2.0)])
+            )
+        );
+        let b = BBox::new([(1.0, -3.0), (2.0, 5.0)]);
+        assert_eq!(b.longest_axis(), 1);
+        assert_eq!(
+            b.split_longest(),
+            (
+                BBox::new([(1.0, -3.0), (2.0, 1.0)]),
+                BBox::new([(1.0, 1.0), (2.0, 5.0)])
+            )
+        );
+    }
+
+    #[test]
+    fn overlap_area() {
+        let a = BBox::new([(0.0, 0.0), (4.0, 3.0)]);
+        assert_eq!(a.overlap_area(a), 12.0);
+        let b = BBox::new([(1.0, 1.0), (2.0, 2.0)]);
+        assert_eq!(a.overlap_area(b), 1.0);
+        assert_eq!(b.overlap_area(a), 1.0);
+        let c = BBox::new([(2.0, -1.0), (6.0, 2.0)]);
+        assert_eq!(a.overlap_area(c), 4.0);
+        let d = BBox::new([(5.0, 0.0), (6.0, 2.0)]);
+        assert_eq!(a.overlap_area(d), 0.0);
+        assert_eq!(a.overlap_area(BBox::default()), 0.0);
+    }
+
+    #[test]
+    fn inset_fraction() {
+        let b = BBox::new([(0.0, 10.0), (100.0, 30.0)]);
+        assert_eq!(
+            b.inset_fraction(0.1, 0.1),
+            BBox::new([(10.0, 12.0), (90.0, 28.0)])
+        );
+        let c = b.inset_fraction(0.5, 0.0);
+        assert_eq!(c, BBox::new([(50.0, 10.0), (50.0, 30.0)]));
+        assert_eq!(c.x_span(), 0.0);
+    }
+
+    #[test]
+    fn pad_to_aspect() {
+        let a = BBox::new([(0.0, 0.0), (9.0, 9.0)]);
+        let b = a.pad_to_aspect(16.0 / 9.0);
+        assert_eq!(b, BBox::new([(-3.5, 0.0), (12.5, 9.0)]));
+        assert_eq!(b.x_span() / b.y_span(), 16.0 / 9.0);
+        let c = BBox::new([(0.0, 0.0), (1.0, 3.0)]).pad_to_aspect(1.0);
+        assert_eq!(c, BBox::new([(-1.0, 0.0), (2.0, 3.0)]));
+        let d = BBox::new([(0.0, 0.0), (4.0, 1.0)]).pad_to_aspect(2.0);
+        assert_eq!(d, BBox::new([(0.0, -0.5), (4.0, 1.5)]));
+        assert_eq!(d.pad_to_aspect(2.0), d);
+    }
+
+    #[test]
+    fn to_square() {
+        let a = BBox::new([(0.0, 1.0), (8.0, 3.0)]);
+        let b = a.to_square();
+        assert_eq!(b, BBox::new([(0.0, -2.0), (8.0, 6.0)]));
+        assert_eq!((b.x_mid(), b.y_mid()), (a.x_mid(), a.y_mid()));
+        assert!(a.bounded_by(b));
+        let c = BBox::new([(1.0, 0.0), (2.0, 5.0)]);
+        let d = c.to_square();
+        assert_eq!(d, BBox::new([(-1.0, 0.0), (4.0, 5.0)]));
+        assert_eq!((d.x_mid(), d.y_mid()), (c.x_mid(), c.y_mid()));
+        assert!(c.bounded_by(d));
+    }
+
+    #[test]
+    fn clamp_within() {
+        let c = BBox::new([(0.0, 0.0), (10.0, 10.0)]);
+        let a = BBox::new([(8.0, 2.0), (12.0, 4.0)]);
+        assert_eq!(a.clamp_within(c), BBox::new([(6.0, 2.0), (10.0, 4.0)]));
+        let b = BBox::new([(-3.0, -1.0), (1.0, 3.0)]);
+        assert_eq!(b.clamp_within(c), BBox::new([(0.0, 0.0), (4.0, 4.0)]));
+        let d = BBox::new([(2.0, 2.0), (4.0, 4.0)]);
+        assert_eq!(d.clamp_within(c), d);
+        let e = BBox::new([(7.0, 8.0), (21.0, 10.0)]);
+        assert_eq!(e.clamp_within(c), BBox::new([(-2.0, 8.0), (12.0, 10.0)]));
+    }
+
+    #[test]
+    fn corners() {
+        let b = BBox::new([(1.0, 2.0), (4.0, 3.0)]);
+        let cw = b.corners_cw();
+        let ccw = b.corners_ccw();
+        assert_eq!(cw[0], Pt::new(1.0, 2.0));
+        assert_eq!(cw[1], Pt::new(1.0, 3.0));
+        assert_eq!(ccw[0], Pt::new(1.0, 2.0));
+        assert_eq!(ccw[1], Pt::new(4.0, 2.0));
+        let area = |c: [Pt<f64>; 4]| {
+            (0..4).map(|i| c[i] * c[(i + 1) % 4]).sum::<f64>() / 2.0
+        };
+        assert_eq!(area(cw), -3.0);
+        assert_eq!(area(ccw), 3.0);
+    }
+
+    #[test]
+    fn round() {
+        let b = BBox::new([(1.5, -2.5), (9.0, 13.5)]);
+        let r = b.round_out(4.0);
+        assert_eq!(r, BBox::new([(0.0, -4.0), (12.0, 16.0)]));
+        let r = b.round_in(4.0);
+        assert_eq!(r, BBox::new([(4.0, 0.0), (8.0, 12.0)]));
+        let r = b.round_in(1.0);
+        assert_eq!(r, BBox::new([(2.0, -2.0), (9.0, 13.0)]));
+        let r = BBox::new([(1.5, 1.5), (2.5, 2.5)]).round_out(1.0);
+        assert_eq!(r, BBox::new([(1.0, 1.0), (3.0, 3.0)]));
+    }
+
     #[test]
     fn extend() {
         let mut a = BBox::new([(0.0, 0.0), (1.0, 1.0)]);
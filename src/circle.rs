@@ -0,0 +1,116 @@
+// circle.rs    2D Circles
+//
+// Copyright (c) 2024  Douglas P Lau
+//
+use crate::bbox::BBox;
+use crate::float::Float;
+use crate::point::Pt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A circle
+///
+/// ```rust
+/// use pointy::Circle;
+///
+/// let circle = Circle::new((10.0, 15.0), 5.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Circle<F>
+where
+    F: Float,
+{
+    /// Center point
+    pub center: Pt<F>,
+
+    /// Radius
+    pub radius: F,
+}
+
+impl<F> Circle<F>
+where
+    F: Float,
+{
+    /// Create a new circle
+    pub fn new<P>(center: P, radius: F) -> Self
+    where
+        P: Into<Pt<F>>,
+    {
+        let center = center.into();
+        Self { center, radius }
+    }
+
+    /// Create the circle passing through three points.
+    ///
+    /// Returns `None` if the points are collinear.
+    pub fn from_points<P>(a: P, b: P, c: P) -> Option<Self>
+    where
+        P: Into<Pt<F>>,
+    {
+        let a = a.into();
+        let ba = b.into() - a;
+        let ca = c.into() - a;
+        let den = (ba * ca) * (F::one() + F::one());
+        if den == F::zero() {
+            return None;
+        }
+        let (bb, cc) = (ba.dot(ba), ca.dot(ca));
+        let u = Pt::new(ca.y * bb - ba.y * cc, ba.x * cc - ca.x * bb) / den;
+        Some(Self::new(a + u, u.mag()))
+    }
+}
+
+impl<F> BBox<F>
+where
+    F: Float,
+{
+    /// Check if the bounding box intersects a circle.
+    ///
+    /// An empty box never intersects.
+    pub fn intersects_circle(self, circle: Circle<F>) -> bool {
+        // clamp requires ordered (non-NaN) bounds
+        if !(self.x_min() <= self.x_max() && self.y_min() <= self.y_max()) {
+            return false;
+        }
+        let c = circle.center;
+        let x = c.x.clamp(self.x_min(), self.x_max());
+        let y = c.y.clamp(self.y_min(), self.y_max());
+        c.distance_sq((x, y)) <= circle.radius * circle.radius
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn from_points() {
+        let c =
+            Circle::from_points((0.0, 0.0), (4.0, 0.0), (0.0, 3.0)).unwrap();
+        assert_eq!(c, Circle::new((2.0, 1.5), 2.5));
+        let pts =
+            [Pt::new(1.0f64, 2.0), Pt::new(-3.0, 0.5), Pt::new(2.5, -4.0)];
+        let c = Circle::from_points(pts[0], pts[1], pts[2]).unwrap();
+        for p in pts {
+            assert_approx_eq!(p.distance(c.center), c.radius);
+        }
+        let c = Circle::from_points((0.0, 0.0), (1.0, 1.0), (3.0, 3.0));
+        assert_eq!(c, None);
+    }
+
+    #[test]
+    fn intersects_circle() {
+        let b = BBox::new([(0.0, 0.0), (10.0, 10.0)]);
+        assert!(b.intersects_circle(Circle::new((5.0, 5.0), 1.0)));
+        assert!(b.intersects_circle(Circle::new((5.0, 5.0), 20.0)));
+        assert!(b.intersects_circle(Circle::new((12.0, 5.0), 3.0)));
+        assert!(!b.intersects_circle(Circle::new((12.0, 5.0), 1.5)));
+        assert!(b.intersects_circle(Circle::new((13.0, 14.0), 5.0)));
+        assert!(!b.intersects_circle(Circle::new((13.0, 14.0), 4.9)));
+        assert!(!b.intersects_circle(Circle::new((-5.0, 20.0), 2.0)));
+        let e = BBox::default();
+        assert!(!e.intersects_circle(Circle::new((0.0, 0.0), 1.0)));
+    }
+}
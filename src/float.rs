@@ -6,6 +6,11 @@ use std::fmt::Debug;
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// Floating point component type
+///
+/// Methods such as `clamp` are provided by [num_traits::Float]; defining
+/// them here too would make calls ambiguous.  Note that `clamp` panics in
+/// debug builds if its lower bound is greater than the upper bound, and
+/// returns NaN for a NaN value.
 pub trait Float:
     num_traits::Float
     + num_traits::FloatConst
@@ -26,7 +31,75 @@ pub trait Float:
     fn lerp(self, rhs: Self, t: Self) -> Self {
         rhs + (self - rhs) * t
     }
+
+    /// Calculate interpolation of two angles (radians).
+    ///
+    /// Interpolation takes the shortest path around the circle, from
+    /// `self` (when t is 0) to `rhs` (when t is 1).  Note this is the
+    /// opposite of [lerp](Self::lerp), which returns `rhs` when t is 0.
+    /// The result will be between `-PI` and `+PI`.
+    fn lerp_angle(self, rhs: Self, t: Self) -> Self {
+        let wrap = |th: Self| {
+            if th > Self::PI() {
+                th - Self::TAU()
+            } else if th < -Self::PI() {
+                th + Self::TAU()
+            } else {
+                th
+            }
+        };
+        let d = wrap((rhs - self) % Self::TAU());
+        wrap(self + d * t)
+    }
 }
 
 impl Float for f32 {}
 impl Float for f64 {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::*;
+
+    fn clamp<F: Float>(v: F, lo: F, hi: F) -> F {
+        v.clamp(lo, hi)
+    }
+
+    #[test]
+    fn clamp_f32() {
+        assert_eq!(clamp(-1.5f32, 0.0, 1.0), 0.0);
+        assert_eq!(clamp(0.25f32, 0.0, 1.0), 0.25);
+        assert_eq!(clamp(3.0f32, 0.0, 1.0), 1.0);
+        assert!(clamp(f32::NAN, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn clamp_f64() {
+        assert_eq!(clamp(-1.5f64, -1.0, 1.0), -1.0);
+        assert_eq!(clamp(0.75f64, -1.0, 1.0), 0.75);
+        assert_eq!(clamp(2.0f64, -1.0, 1.0), 1.0);
+        assert!(clamp(f64::NAN, -1.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn lerp_angle() {
+        use std::f64::consts::PI;
+        assert_approx_eq!(0.5f64.lerp_angle(1.5, 0.5), 1.0);
+        assert_approx_eq!(0.5f64.lerp_angle(1.5, 0.0), 0.5);
+        assert_approx_eq!(0.5f64.lerp_angle(1.5, 1.0), 1.5);
+        assert_approx_eq!(3.0f64.lerp_angle(-3.0, 0.5).abs(), PI);
+        assert_approx_eq!(3.0f64.lerp_angle(-3.0, 1.0), -3.0);
+        assert_approx_eq!(
+            3.0f64.lerp_angle(-3.0, 0.25),
+            3.0 + (PI - 3.0) / 2.0
+        );
+        assert_approx_eq!(
+            (-3.0f64).lerp_angle(3.0, 0.25),
+            -3.0 - (PI - 3.0) / 2.0
+        );
+        let a = 350.0f32.to_radians();
+        let b = 10.0f32.to_radians();
+        assert_approx_eq!(a.lerp_angle(b, 0.5), 0.0);
+        assert_approx_eq!(a.lerp_angle(b, 0.75), 5.0f32.to_radians());
+    }
+}
@@ -6,13 +6,21 @@
 #![warn(missing_docs)]
 
 mod bbox;
+mod circle;
 mod float;
 mod line;
 mod point;
+mod polygon;
 mod transform;
 
 pub use bbox::{BBox, BBoxIter, Bounded, Bounds};
+pub use circle::Circle;
 pub use float::Float;
-pub use line::{Line, Seg};
-pub use point::Pt;
-pub use transform::Transform;
+pub use line::{
+    bounds_of, intersections, path_point_at_distance, Line, Ray, Seg,
+};
+pub use point::{
+    bilerp, centroid, dedup_points, sort_by_angle, HashPt, Pt, RoundMode,
+};
+pub use polygon::{clip_polygon, FillRule, Polygon};
+pub use transform::{transform_points_bounds, Transform};
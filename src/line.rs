@@ -5,6 +5,7 @@
 use crate::bbox::{BBox, Bounded, Bounds};
 use crate::float::Float;
 use crate::point::Pt;
+use crate::transform::Transform;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -48,6 +49,46 @@ where
     pub p1: Pt<F>,
 }
 
+/// A ray, with an origin and a unit direction vector
+///
+/// ```rust
+/// use pointy::Ray;
+///
+/// let ray = Ray::new((10.0, 15.0), (0.0, 2.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Ray<F>
+where
+    F: Float,
+{
+    /// Origin point
+    pub origin: Pt<F>,
+
+    /// Direction (unit vector)
+    pub dir: Pt<F>,
+}
+
+impl<F> Ray<F>
+where
+    F: Float,
+{
+    /// Create a new ray
+    ///
+    /// * `origin` Origin point.
+    /// * `dir` Direction vector (normalized to unit length).
+    pub fn new<P0, P1>(origin: P0, dir: P1) -> Self
+    where
+        P0: Into<Pt<F>>,
+        P1: Into<Pt<F>>,
+    {
+        Self {
+            origin: origin.into(),
+            dir: dir.into().normalize(),
+        }
+    }
+}
+
 impl<F> Line<F>
 where
     F: Float,
@@ -66,13 +107,82 @@ where
 
     /// Get the distance from the line to a point
     pub fn distance<P>(self, pt: P) -> F
+    where
+        P: Into<Pt<F>>,
+    {
+        self.signed_distance(pt).abs()
+    }
+
+    /// Get the signed distance from the line to a point.
+    ///
+    /// The distance is positive for points to the left of the line,
+    /// looking from `p0` toward `p1`.
+    pub fn signed_distance<P>(self, pt: P) -> F
+    where
+        P: Into<Pt<F>>,
+    {
+        let pt = pt.into();
+        let v0 = self.p1 - self.p0;
+        let v1 = pt - self.p0;
+        (v0 * v1) / v0.mag()
+    }
+
+    /// Get signed distances at evenly spaced points along a row.
+    ///
+    /// Samples start at `x_start` and increase by `step` up to `x_end`
+    /// (inclusive).  Since the distance is linear in X, each value is
+    /// found by incrementing the previous one.  A non-positive `step`
+    /// yields no samples.
+    ///
+    /// * `y` Row Y coordinate.
+    /// * `x_start` First sample X coordinate.
+    /// * `x_end` Last sample X coordinate.
+    /// * `step` Distance between samples.
+    pub fn coverage_row(
+        self,
+        y: F,
+        x_start: F,
+        x_end: F,
+        step: F,
+    ) -> impl Iterator<Item = F> {
+        let n = if step > F::zero() && x_end >= x_start {
+            ((x_end - x_start) / step).floor().to_usize().unwrap_or(0) + 1
+        } else {
+            0
+        };
+        let v = self.p1 - self.p0;
+        let d0 = self.signed_distance((x_start, y));
+        let dd = -v.y * step / v.mag();
+        std::iter::successors(Some(d0), move |d| Some(*d + dd)).take(n)
+    }
+
+    /// Check if a point is within the half-plane left of the line.
+    ///
+    /// Left is relative to the line direction, looking from `p0` toward
+    /// `p1`; this matches a non-negative
+    /// [signed distance](Self::signed_distance).  Points on the line are
+    /// contained.
+    pub fn half_plane_contains<P>(self, pt: P) -> bool
+    where
+        P: Into<Pt<F>>,
+    {
+        (self.p1 - self.p0) * (pt.into() - self.p0) >= F::zero()
+    }
+
+    /// Get the foot of the perpendicular from a point to the line.
+    ///
+    /// Returns the projected point along with the
+    /// [signed distance](Self::signed_distance).
+    pub fn foot<P>(self, pt: P) -> (Pt<F>, F)
     where
         P: Into<Pt<F>>,
     {
         let pt = pt.into();
         let v0 = self.p1 - self.p0;
         let v1 = pt - self.p0;
-        (v0 * v1).abs() / v0.mag()
+        let m = v0.mag();
+        let t = v0.dot(v1) / (m * m);
+        (self.p0 + v0 * t, (v0 * v1) / m)
     }
 
     /// Get the point where two lines intersect
@@ -92,6 +202,119 @@ where
         }
     }
 
+    /// Get the intersection point with a segment.
+    ///
+    /// Returns `None` if the line does not cross the segment, or if they
+    /// are parallel (including collinear).
+    pub fn intersect_segment(self, seg: Seg<F>) -> Option<Pt<F>> {
+        let zero = F::zero();
+        let v = self.p1 - self.p0;
+        let d0 = v * (seg.p0 - self.p0);
+        let d1 = v * (seg.p1 - self.p0);
+        if d0 == d1 || (d0 > zero && d1 > zero) || (d0 < zero && d1 < zero) {
+            return None;
+        }
+        if d0 == zero {
+            Some(seg.p0)
+        } else if d1 == zero {
+            Some(seg.p1)
+        } else {
+            Some(seg.p0 + (seg.p1 - seg.p0) * (d0 / (d0 - d1)))
+        }
+    }
+
+    /// Create a line from Hesse normal form.
+    ///
+    /// Points on the line satisfy `normal.dot(pt) == offset`.  The line is
+    /// directed so that `normal` points to its left side.
+    ///
+    /// * `normal` Unit normal vector.
+    /// * `offset` Signed distance from the origin.
+    pub fn from_normal_offset<P>(normal: P, offset: F) -> Self
+    where
+        P: Into<Pt<F>>,
+    {
+        let normal = normal.into();
+        let p0 = normal * offset;
+        Self::new(p0, p0 + normal.right())
+    }
+
+    /// Convert to Hesse normal form.
+    ///
+    /// Returns the unit normal (pointing to the left side) and the signed
+    /// distance from the origin.
+    pub fn to_normal_offset(self) -> (Pt<F>, F) {
+        let normal = (self.p1 - self.p0).normalize().left();
+        (normal, normal.dot(self.p0))
+    }
+
+    /// Convert to a ray from the first point toward the second
+    pub fn to_ray(self) -> Ray<F> {
+        Ray::new(self.p0, self.p1 - self.p0)
+    }
+
+    /// Reflect a point across the line
+    pub fn reflect<P>(self, pt: P) -> Pt<F>
+    where
+        P: Into<Pt<F>>,
+    {
+        let pt = pt.into();
+        let (foot, _) = self.foot(pt);
+        foot + (foot - pt)
+    }
+
+    /// Get a transform which reflects across the line.
+    ///
+    /// This is useful for reflecting many points across the same line.
+    pub fn reflect_transform(self) -> Transform<F> {
+        Transform::with_reflect_line(self)
+    }
+
+    /// Check if two lines are the same, regardless of direction.
+    ///
+    /// The comparison is exact: both points of each line must lie exactly
+    /// on the other line, with no tolerance for rounding.  Degenerate
+    /// lines (with equal points) are never the same.
+    pub fn same_geometry(self, rhs: Self) -> bool {
+        let contains = |line: Self, pt: Pt<F>| {
+            (line.p1 - line.p0) * (pt - line.p0) == F::zero()
+        };
+        self.p0 != self.p1
+            && rhs.p0 != rhs.p1
+            && contains(self, rhs.p0)
+            && contains(self, rhs.p1)
+            && contains(rhs, self.p0)
+            && contains(rhs, self.p1)
+    }
+
+    /// Check if the line touches a bounding box only at one corner.
+    ///
+    /// Returns the corner if it is within `epsilon` of the line, and all
+    /// other corners are farther than `epsilon` on the same side.
+    pub fn touches_corner(self, bbox: BBox<F>, epsilon: F) -> Option<Pt<F>> {
+        let corners = bbox.corners_ccw();
+        let dist = corners.map(|c| self.signed_distance(c));
+        let i = dist.iter().position(|d| d.abs() <= epsilon)?;
+        let others = (1..4).map(|j| dist[(i + j) % 4]);
+        if others.clone().all(|d| d > epsilon)
+            || others.clone().all(|d| d < -epsilon)
+        {
+            Some(corners[i])
+        } else {
+            None
+        }
+    }
+
+    /// Get the angle between two lines.
+    ///
+    /// Since lines are undirected, the result will be between `0` and
+    /// `PI / 2`.
+    pub fn angle_between(self, rhs: Self) -> F {
+        let v0 = self.p1 - self.p0;
+        let v1 = rhs.p1 - rhs.p0;
+        (v0 * v1).abs().atan2(v0.dot(v1).abs())
+    }
+
     /// Project a point onto the line.
     ///
     /// Returns the point on the line nearest to the given point.
@@ -108,6 +331,15 @@ where
     }
 }
 
+impl<F> From<Seg<F>> for BBox<F>
+where
+    F: Float,
+{
+    fn from(seg: Seg<F>) -> Self {
+        Self::new([seg.p0, seg.p1])
+    }
+}
+
 impl<F> Bounded<F> for Seg<F>
 where
     F: Float,
@@ -206,12 +438,285 @@ where
         (v0 * v3).abs() / v0.mag()
     }
 
-    /// Get the point where two segments intersect
-    pub fn intersection(self, rhs: Self) -> Option<Pt<F>> {
-        let l0 = Line::new(self.p0, self.p1);
-        let l1 = Line::new(rhs.p0, rhs.p1);
+    /// Convert to a ray from the first point toward the second
+    pub fn to_ray(self) -> Ray<F> {
+        Ray::new(self.p0, self.p1 - self.p0)
+    }
+
+    /// Convert to a quadratic Bézier curve.
+    ///
+    /// Returns the start, control and end points; the control point is
+    /// the midpoint, so the curve is a straight line.
+    pub fn to_quadratic(self) -> (Pt<F>, Pt<F>, Pt<F>) {
+        (self.p0, self.p0.midpoint(self.p1), self.p1)
+    }
+
+    /// Check if two segments are the same, regardless of point order
+    pub fn same_geometry(self, rhs: Self) -> bool {
+        (self.p0 == rhs.p0 && self.p1 == rhs.p1)
+            || (self.p0 == rhs.p1 && self.p1 == rhs.p0)
+    }
+
+    /// Check if the segment is oriented.
+    ///
+    /// A segment is oriented if `p0` is lexicographically less than or
+    /// equal to `p1`, comparing X first and then Y.
+    pub fn is_oriented(self) -> bool {
+        self.p0.x < self.p1.x
+            || (self.p0.x == self.p1.x && self.p0.y <= self.p1.y)
+    }
+
+    /// Get the segment with oriented endpoints.
+    ///
+    /// See [is_oriented](Self::is_oriented).
+    pub fn oriented(self) -> Self {
+        if self.is_oriented() {
+            self
+        } else {
+            Self::new(self.p1, self.p0)
+        }
+    }
+
+    /// Check if the segment length is less than `epsilon`
+    pub fn is_degenerate(self, epsilon: F) -> bool {
+        self.p0.distance(self.p1) < epsilon
+    }
+
+    /// Get the unit normal vector.
+    ///
+    /// This is the left-hand perpendicular of the segment direction.  A
+    /// zero-length segment returns a zero vector.
+    pub fn normal(self) -> Pt<F> {
+        (self.p1 - self.p0).normalize().left()
+    }
+
+    /// Get the direction bisecting the angle with the next segment.
+    ///
+    /// The segments should be joined, with `self.p1` equal to `next.p0`.
+    /// Returns a unit vector from the shared vertex bisecting the interior
+    /// angle.  For a straight continuation, the left-hand normal is
+    /// returned.
+    pub fn bisector_direction(self, next: Self) -> Pt<F> {
+        let v0 = (self.p0 - self.p1).normalize();
+        let v1 = (next.p1 - next.p0).normalize();
+        let v = v0 + v1;
+        if v.mag() > F::epsilon() {
+            v.normalize()
+        } else {
+            v1.left()
+        }
+    }
+
+    /// Get the miter point joining with the next segment.
+    ///
+    /// Both segments are offset along their [normal](Self::normal)s, and
+    /// the intersection of the offset lines is returned.  Returns `None` if
+    /// the segments are parallel.
+    ///
+    /// * `next` Following segment, starting at `self.p1`.
+    /// * `offset` Offset distance (positive to the left).
+    pub fn miter_point(self, next: Self, offset: F) -> Option<Pt<F>> {
+        let n0 = self.normal() * offset;
+        let n1 = next.normal() * offset;
+        let l0 = Line::new(self.p0 + n0, self.p1 + n0);
+        let l1 = Line::new(next.p0 + n1, next.p1 + n1);
         l0.intersection(l1)
-            .filter(|p| p.bounded_by(BBox::new([rhs.p0, rhs.p1])))
+    }
+
+    /// Get the fraction along the segment nearest to a point.
+    ///
+    /// Returns a value from 0 (at `p0`) to 1 (at `p1`).  A zero-length
+    /// segment always returns 0, and NaN coordinates result in NaN.
+    pub fn fraction_at<P>(self, pt: P) -> F
+    where
+        P: Into<Pt<F>>,
+    {
+        let v0 = self.p1 - self.p0;
+        let v1 = pt.into() - self.p0;
+        let len_sq = v0.dot(v0);
+        if len_sq > F::zero() {
+            (v0.dot(v1) / len_sq).clamp(F::zero(), F::one())
+        } else {
+            F::zero()
+        }
+    }
+
+    /// Get evenly spaced points along the segment.
+    ///
+    /// The segment is divided into `n` intervals (at least 1), and the
+    /// endpoints can be excluded to avoid duplicates when joining
+    /// segments.
+    ///
+    /// * `n` Number of intervals.
+    /// * `include_start` Include `p0` as the first point.
+    /// * `include_end` Include `p1` as the last point.
+    pub fn interpolate(
+        self,
+        n: usize,
+        include_start: bool,
+        include_end: bool,
+    ) -> Vec<Pt<F>> {
+        let n = n.max(1);
+        let v = self.p1 - self.p0;
+        let first = usize::from(!include_start);
+        let last = if include_end { n } else { n - 1 };
+        (first..=last)
+            .map(|i| {
+                if i == n {
+                    self.p1
+                } else {
+                    let t = F::from(i).unwrap() / F::from(n).unwrap();
+                    self.p0 + v * t
+                }
+            })
+            .collect()
+    }
+
+    /// Interpolate endpoints toward another segment.
+    ///
+    /// * `rhs` Segment to interpolate toward.
+    /// * `t` Interpolation amount, from 0 (`self`) to 1 (`rhs`).
+    pub fn lerp(self, rhs: Self, t: F) -> Self {
+        let p0 = self.p0 + (rhs.p0 - self.p0) * t;
+        let p1 = self.p1 + (rhs.p1 - self.p1) * t;
+        Self::new(p0, p1)
+    }
+
+    /// Get the Y value where the segment crosses an X value.
+    ///
+    /// Returns `None` if `x` is outside the segment's X range, or if the
+    /// segment is vertical.
+    pub fn y_at_x(self, x: F) -> Option<F> {
+        let (x0, x1) = (self.p0.x.min(self.p1.x), self.p0.x.max(self.p1.x));
+        if x0 == x1 || x < x0 || x > x1 {
+            return None;
+        }
+        let t = (x - self.p0.x) / (self.p1.x - self.p0.x);
+        Some(self.p0.y + (self.p1.y - self.p0.y) * t)
+    }
+
+    /// Get the X value where the segment crosses a Y value.
+    ///
+    /// Returns `None` if `y` is outside the segment's Y range, or if the
+    /// segment is horizontal.
+    pub fn x_at_y(self, y: F) -> Option<F> {
+        let (y0, y1) = (self.p0.y.min(self.p1.y), self.p0.y.max(self.p1.y));
+        if y0 == y1 || y < y0 || y > y1 {
+            return None;
+        }
+        let t = (y - self.p0.y) / (self.p1.y - self.p0.y);
+        Some(self.p0.x + (self.p1.x - self.p0.x) * t)
+    }
+
+    /// Get the closest points between two segments.
+    ///
+    /// Returns the nearest point on this segment and the nearest point on
+    /// `rhs`.  If the segments intersect, both points are the same.  NaN
+    /// coordinates result in NaN points.
+    pub fn closest_points(self, rhs: Self) -> (Pt<F>, Pt<F>) {
+        let zero = F::zero();
+        let one = F::one();
+        let d0 = self.p1 - self.p0;
+        let d1 = rhs.p1 - rhs.p0;
+        let r = self.p0 - rhs.p0;
+        let a = d0.dot(d0);
+        let e = d1.dot(d1);
+        let f = d1.dot(r);
+        let (s, t) = if a <= zero && e <= zero {
+            // both segments are points
+            (zero, zero)
+        } else if a <= zero {
+            (zero, (f / e).clamp(zero, one))
+        } else {
+            let c = d0.dot(r);
+            if e <= zero {
+                ((-c / a).clamp(zero, one), zero)
+            } else {
+                let b = d0.dot(d1);
+                let den = a * e - b * b;
+                // parallel segments have a zero denominator
+                let s = if den != zero {
+                    ((b * f - c * e) / den).clamp(zero, one)
+                } else {
+                    zero
+                };
+                let t = (b * s + f) / e;
+                if t < zero {
+                    ((-c / a).clamp(zero, one), zero)
+                } else if t > one {
+                    (((b - c) / a).clamp(zero, one), one)
+                } else {
+                    (s, t)
+                }
+            }
+        };
+        (self.p0 + d0 * s, rhs.p0 + d1 * t)
+    }
+
+    /// Check if a point lies on the segment
+    fn touches(self, pt: Pt<F>) -> bool {
+        (pt - self.p0) * (self.p1 - self.p0) == F::zero()
+            && pt.bounded_by(BBox::new([self.p0, self.p1]))
+    }
+
+    /// Get the point where two segments intersect.
+    ///
+    /// Segments which touch (including at an endpoint) are considered
+    /// intersecting.  If an endpoint of either segment lies on the other,
+    /// that endpoint is returned exactly.  For overlapping collinear
+    /// segments, one endpoint of the overlap is returned.
+    pub fn intersection(self, rhs: Self) -> Option<Pt<F>> {
+        for pt in [self.p0, self.p1] {
+            if rhs.touches(pt) {
+                return Some(pt);
+            }
+        }
+        for pt in [rhs.p0, rhs.p1] {
+            if self.touches(pt) {
+                return Some(pt);
+            }
+        }
+        let v0 = self.p1 - self.p0;
+        let v1 = rhs.p1 - rhs.p0;
+        let den = v0 * v1;
+        if den == F::zero() {
+            return None;
+        }
+        let v2 = rhs.p0 - self.p0;
+        let t = (v2 * v1) / den;
+        let u = (v2 * v0) / den;
+        let range = F::zero()..=F::one();
+        if range.contains(&t) && range.contains(&u) {
+            Some(self.p0 + v0 * t)
+        } else {
+            None
+        }
+    }
+
+    /// Check if segment intersects with another segment, using orientation
+    /// tests.
+    ///
+    /// No intersection point is calculated, and touching endpoints,
+    /// T-junctions and collinear overlaps are all considered intersecting.
+    /// The orientation tests use ordinary floating-point arithmetic (not
+    /// exact predicates), so results for nearly parallel or nearly
+    /// touching segments are subject to rounding.
+    pub fn intersects_robust(self, rhs: Self) -> bool {
+        let zero = F::zero();
+        let orient = |a: Pt<F>, b: Pt<F>, c: Pt<F>| (b - a) * (c - a);
+        let d0 = orient(rhs.p0, rhs.p1, self.p0);
+        let d1 = orient(rhs.p0, rhs.p1, self.p1);
+        let d2 = orient(self.p0, self.p1, rhs.p0);
+        let d3 = orient(self.p0, self.p1, rhs.p1);
+        if ((d0 > zero && d1 < zero) || (d0 < zero && d1 > zero))
+            && ((d2 > zero && d3 < zero) || (d2 < zero && d3 > zero))
+        {
+            return true;
+        }
+        rhs.touches(self.p0)
+            || rhs.touches(self.p1)
+            || self.touches(rhs.p0)
+            || self.touches(rhs.p1)
     }
 
     /// Check if segment intersects with another segment
@@ -219,8 +724,40 @@ where
         self.intersection(rhs).is_some()
     }
 
+    /// Split segment where it intersects another segment.
+    ///
+    /// Returns two segments if the intersection is between the endpoints,
+    /// otherwise the original segment.
+    pub fn split_at_intersection(self, other: Self) -> Vec<Self> {
+        match self.intersection(other) {
+            Some(pt) if pt != self.p0 && pt != self.p1 => {
+                vec![Self::new(self.p0, pt), Self::new(pt, self.p1)]
+            }
+            _ => vec![self],
+        }
+    }
+
     /// Clip segment with a bounding box
     pub fn clip(mut self, bbox: BBox<F>) -> Option<Self> {
+        // a segment touching only one corner must clip to that point,
+        // regardless of rounding errors in edge intersections, which scale
+        // with coordinate magnitude
+        let scale = [
+            self.p0.x,
+            self.p0.y,
+            self.p1.x,
+            self.p1.y,
+            bbox.x_min(),
+            bbox.y_min(),
+            bbox.x_max(),
+            bbox.y_max(),
+        ]
+        .iter()
+        .fold(F::zero(), |m, v| m.max(v.abs()));
+        let eps = F::epsilon() * scale;
+        if let Some(c) = Line::new(self.p0, self.p1).touches_corner(bbox, eps) {
+            return (self.distance(c) <= eps).then(|| Self::new(c, c));
+        }
         if !self.bounded_by(bbox) {
             return None;
         }
@@ -258,12 +795,73 @@ where
         }
         Some(self)
     }
+
+    /// Clip segment with a convex polygon.
+    ///
+    /// The polygon vertices must be in counter-clockwise order.  Returns
+    /// `None` if no part of the segment is inside the polygon.
+    pub fn clip_convex(self, polygon: &[Pt<F>]) -> Option<Self> {
+        if polygon.len() < 3 {
+            return None;
+        }
+        let d = self.p1 - self.p0;
+        let mut t0 = F::zero();
+        let mut t1 = F::one();
+        for (i, a) in polygon.iter().enumerate() {
+            let b = polygon[(i + 1) % polygon.len()];
+            let e = b - *a;
+            // inside is to the left of each edge
+            let num = e * (self.p0 - *a);
+            let den = e * d;
+            if den == F::zero() {
+                if num < F::zero() {
+                    return None;
+                }
+            } else {
+                let t = -num / den;
+                if den > F::zero() {
+                    t0 = t0.max(t);
+                } else {
+                    t1 = t1.min(t);
+                }
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        Some(Self::new(self.p0 + d * t0, self.p0 + d * t1))
+    }
 }
 
 impl<F> BBox<F>
 where
     F: Float,
 {
+    /// Extend bounding box to include a segment
+    pub fn include_seg(&mut self, seg: Seg<F>) {
+        self.include(seg);
+    }
+
+    /// Check if a segment is entirely within the bounding box
+    pub fn contains_seg(self, seg: Seg<F>) -> bool {
+        self.check(seg.p0.x, seg.p0.y) == Bounds::Within
+            && self.check(seg.p1.x, seg.p1.y) == Bounds::Within
+    }
+
+    /// Get the four boundary edges.
+    ///
+    /// The edges form a closed counter-clockwise loop, starting from the
+    /// minimum corner along the Y min side.
+    pub fn edges(self) -> [Seg<F>; 4] {
+        let [c0, c1, c2, c3] = self.corners_ccw();
+        [
+            Seg::new(c0, c1),
+            Seg::new(c1, c2),
+            Seg::new(c2, c3),
+            Seg::new(c3, c0),
+        ]
+    }
+
     /// Get edge on X min side
     fn x_min_edge(self) -> Seg<F> {
         let xmn = self.x_min();
@@ -289,9 +887,62 @@ where
     }
 }
 
+/// Get all pairwise intersections of a set of lines.
+///
+/// Pairs of parallel lines are skipped.
+pub fn intersections<F: Float>(lines: &[Line<F>]) -> Vec<Pt<F>> {
+    let mut pts = Vec::new();
+    for (i, l0) in lines.iter().enumerate() {
+        for l1 in &lines[i + 1..] {
+            if let Some(pt) = l0.intersection(*l1) {
+                pts.push(pt);
+            }
+        }
+    }
+    pts
+}
+
+/// Get the bounding box of a set of segments.
+///
+/// An empty slice results in an empty bounding box.
+pub fn bounds_of<F: Float>(segs: &[Seg<F>]) -> BBox<F> {
+    let mut bbox = BBox::default();
+    segs.iter().for_each(|seg| bbox.include(*seg));
+    bbox
+}
+
+/// Get the point at a distance along a polyline.
+///
+/// Returns `None` if `d` is negative or exceeds the total length.
+pub fn path_point_at_distance<F: Float>(pts: &[Pt<F>], d: F) -> Option<Pt<F>> {
+    if d < F::zero() {
+        return None;
+    }
+    let first = pts.first()?;
+    let mut remaining = d;
+    for seg in pts.windows(2) {
+        let len = seg[0].distance(seg[1]);
+        if remaining <= len {
+            let t = if len > F::zero() {
+                remaining / len
+            } else {
+                F::zero()
+            };
+            return Some(seg[0] + (seg[1] - seg[0]) * t);
+        }
+        remaining = remaining - len;
+    }
+    if remaining == F::zero() {
+        Some(*first)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use assert_approx_eq::*;
 
     #[test]
     fn distance() {
@@ -301,6 +952,99 @@ mod test {
         assert_eq!(b.distance((2.0, 0.0)), 2.0);
     }
 
+    #[test]
+    fn signed_distance() {
+        let a = Line::new((0.0, 0.0), (1.0, 0.0));
+        assert_eq!(a.signed_distance((0.0, 1.0)), 1.0);
+        assert_eq!(a.signed_distance((5.0, -2.0)), -2.0);
+        let b = Line::new((0.0, 1.0), (0.0, 0.0));
+        assert_eq!(b.signed_distance((2.0, 0.0)), 2.0);
+        assert_eq!(b.signed_distance((-3.0, 0.0)), -3.0);
+    }
+
+    #[test]
+    fn coverage_row() {
+        let l = Line::new((1.0, 0.5), (4.0, 3.0));
+        let d: Vec<f64> = l.coverage_row(2.5, 0.5, 5.0, 0.5).collect();
+        assert_eq!(d.len(), 10);
+        for (i, d) in d.iter().enumerate() {
+            let x = 0.5 + 0.5 * i as f64;
+            assert_approx_eq!(*d, l.signed_distance((x, 2.5)));
+        }
+        assert_eq!(l.coverage_row(0.0, 1.0, 0.0, 0.5).count(), 0);
+        assert_eq!(l.coverage_row(0.0, 0.0, 1.0, 0.0).count(), 0);
+        assert_eq!(l.coverage_row(0.0, 1.0, 1.0, 0.5).count(), 1);
+    }
+
+    #[test]
+    fn intersect_segment() {
+        let l = Line::new((0.0, 0.0), (1.0, 1.0));
+        let s = Seg::new((0.0, 4.0), (4.0, 0.0));
+        assert_eq!(l.intersect_segment(s), Some(Pt::new(2.0, 2.0)));
+        let s = Seg::new((0.0, 4.0), (1.0, 3.0));
+        assert_eq!(l.intersect_segment(s), None);
+        let s = Seg::new((3.0, 3.0), (5.0, 1.0));
+        assert_eq!(l.intersect_segment(s), Some(Pt::new(3.0, 3.0)));
+        let s = Seg::new((5.0, 1.0), (3.0, 3.0));
+        assert_eq!(l.intersect_segment(s), Some(Pt::new(3.0, 3.0)));
+        let s = Seg::new((0.0, 1.0), (1.0, 2.0));
+        assert_eq!(l.intersect_segment(s), None);
+        let s = Seg::new((5.0, 5.0), (6.0, 6.0));
+        assert_eq!(l.intersect_segment(s), None);
+    }
+
+    #[test]
+    fn normal_offset() {
+        let a = Line::from_normal_offset((0.0, 1.0), 2.0);
+        assert_eq!(a, Line::new((0.0, 2.0), (1.0, 2.0)));
+        assert_eq!(a.to_normal_offset(), (Pt::new(0.0, 1.0), 2.0));
+        assert_eq!(a.signed_distance((5.0, 3.0)), 1.0);
+        let n = Pt::new(1.0f64, 1.0).normalize();
+        let b = Line::from_normal_offset(n, 2.0f64.sqrt());
+        assert_approx_eq!(b.distance((1.0, 1.0)), 0.0);
+        assert_approx_eq!(b.distance((2.0, 0.0)), 0.0);
+        let (n1, d) = b.to_normal_offset();
+        assert_approx_eq!(n1.x, n.x);
+        assert_approx_eq!(n1.y, n.y);
+        assert_approx_eq!(d, 2.0f64.sqrt());
+        let (n1, d) = Line::new((0.0, 2.0), (2.0, 0.0)).to_normal_offset();
+        assert_approx_eq!(n1.x, n.x);
+        assert_approx_eq!(n1.y, n.y);
+        assert_approx_eq!(d, 2.0f64.sqrt());
+    }
+
+    #[test]
+    fn half_plane() {
+        let a = Line::new((0.0, 0.0), (2.0, 1.0));
+        assert!(a.half_plane_contains((0.0, 1.0)));
+        assert!(!a.half_plane_contains((1.0, 0.0)));
+        assert!(a.half_plane_contains((4.0, 2.0)));
+        assert!(a.half_plane_contains((-2.0, -1.0)));
+        let b = Line::new((2.0, 1.0), (0.0, 0.0));
+        assert!(!b.half_plane_contains((0.0, 1.0)));
+        assert!(b.half_plane_contains((1.0, 0.0)));
+        for pt in [(0.0, 1.0), (1.0, 0.0), (3.0, -5.0)] {
+            assert_eq!(a.half_plane_contains(pt), a.signed_distance(pt) >= 0.0);
+        }
+    }
+
+    #[test]
+    fn foot() {
+        let a = Line::new((1.0f64, 1.0), (4.0, 5.0));
+        for pt in [(0.0, 0.0), (3.0, -2.0), (-1.0, 7.0), (4.0, 5.0)] {
+            let (p, d) = a.foot(pt);
+            let q = a.project(pt);
+            assert_approx_eq!(p.x, q.x);
+            assert_approx_eq!(p.y, q.y);
+            assert_approx_eq!(d, a.signed_distance(pt));
+            assert_approx_eq!(d.abs(), a.distance(pt));
+        }
+        let (p, d) = a.foot((5.0, 1.0));
+        assert_approx_eq!(p.x, 2.44);
+        assert_approx_eq!(p.y, 2.92);
+        assert_approx_eq!(d, -3.2);
+    }
+
     #[test]
     fn intersection() {
         let a = Line::new((0.0, 0.0), (1.0, 0.0));
@@ -311,6 +1055,81 @@ mod test {
         assert_eq!(b.intersection(b), None);
     }
 
+    #[test]
+    fn reflect() {
+        let a = Line::new((0.0f64, 1.0), (1.0, 2.0));
+        let p = a.reflect((0.0, 0.0));
+        assert_approx_eq!(p.x, -1.0);
+        assert_approx_eq!(p.y, 1.0);
+        let p = a.reflect((3.0, 4.0));
+        assert_approx_eq!(p.x, 3.0);
+        assert_approx_eq!(p.y, 4.0);
+        let t = a.reflect_transform();
+        for pt in [(0.0, 0.0), (3.0, -2.0), (-1.5, 7.0), (2.0, 3.0)] {
+            let p0 = a.reflect(pt);
+            let p1 = t * Pt::from(pt);
+            assert_approx_eq!(p0.x, p1.x);
+            assert_approx_eq!(p0.y, p1.y);
+        }
+    }
+
+    #[test]
+    fn angle_between() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+        let a = Line::new((0.0, 0.0), (1.0, 0.0));
+        let b = Line::new((5.0, 1.0), (-2.0, 1.0));
+        assert_eq!(a.angle_between(b), 0.0);
+        let c = Line::new((3.0, 3.0), (3.0, -1.0));
+        assert_eq!(a.angle_between(c), FRAC_PI_2);
+        let d = Line::new((0.0, 1.0), (1.0, 0.0));
+        assert_eq!(a.angle_between(d), FRAC_PI_4);
+        assert_eq!(d.angle_between(a), FRAC_PI_4);
+        let e = Line::new((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(e.angle_between(d), FRAC_PI_2);
+    }
+
+    #[test]
+    fn all_intersections() {
+        let a = Line::new((0.0, 0.0), (1.0, 0.0));
+        let b = Line::new((0.0, 0.0), (1.0, 1.0));
+        let c = Line::new((2.0, 0.0), (2.0, 1.0));
+        assert_eq!(
+            intersections(&[a, b, c]),
+            [Pt::new(0.0, 0.0), Pt::new(2.0, 0.0), Pt::new(2.0, 2.0)]
+        );
+        let d = Line::new((0.0, 3.0), (1.0, 3.0));
+        assert_eq!(intersections(&[a, d]), []);
+        assert_eq!(intersections(&[a, d, c]).len(), 2);
+        assert_eq!(intersections::<f32>(&[]), []);
+    }
+
+    #[test]
+    fn segs_bounds() {
+        let segs = [
+            Seg::new((0.0, 0.0), (1.0, 1.0)),
+            Seg::new((-2.0, 0.5), (0.5, 0.5)),
+            Seg::new((3.0, 0.0), (0.0, 4.0)),
+            Seg::new((0.0, -1.0), (5.0, 0.0)),
+        ];
+        assert_eq!(bounds_of(&segs), BBox::new([(-2.0, -1.0), (5.0, 4.0)]));
+        assert_eq!(bounds_of(&segs[..1]), BBox::new([(0.0, 0.0), (1.0, 1.0)]));
+        assert_eq!(bounds_of::<f32>(&[]), BBox::default());
+    }
+
+    #[test]
+    fn path_distance() {
+        let path = [Pt::new(0.0, 0.0), Pt::new(4.0, 0.0), Pt::new(4.0, 3.0)];
+        assert_eq!(path_point_at_distance(&path, 0.0), Some(path[0]));
+        assert_eq!(path_point_at_distance(&path, 2.5), Some(Pt::new(2.5, 0.0)));
+        assert_eq!(path_point_at_distance(&path, 4.0), Some(path[1]));
+        assert_eq!(path_point_at_distance(&path, 5.0), Some(Pt::new(4.0, 1.0)));
+        assert_eq!(path_point_at_distance(&path, 7.0), Some(path[2]));
+        assert_eq!(path_point_at_distance(&path, 7.5), None);
+        assert_eq!(path_point_at_distance(&path, -1.0), None);
+        assert_eq!(path_point_at_distance(&path[..1], 0.0), Some(path[0]));
+        assert_eq!(path_point_at_distance::<f32>(&[], 0.0), None);
+    }
+
     #[test]
     fn projection() {
         let d = Line::new((0.0, 0.0), (10.0, 0.0));
@@ -337,16 +1156,360 @@ mod test {
         assert_eq!(a.distance((10.0, -5.0)), 5.0);
     }
 
+    #[test]
+    fn to_quadratic() {
+        let s = Seg::new((1.0, 2.0), (4.0, 6.0));
+        let (p0, c, p1) = s.to_quadratic();
+        assert_eq!(p0, s.p0);
+        assert_eq!(c, Pt::new(2.5, 4.0));
+        assert_eq!(p1, s.p1);
+    }
+
+    #[test]
+    fn to_ray() {
+        let r = Seg::new((1.0, 2.0), (4.0, 6.0)).to_ray();
+        assert_eq!(r.origin, Pt::new(1.0, 2.0));
+        assert_eq!(r.dir, Pt::new(0.6, 0.8));
+        let r = Line::new((3.0, 3.0), (3.0, -1.0)).to_ray();
+        assert_eq!(r.origin, Pt::new(3.0, 3.0));
+        assert_eq!(r.dir, Pt::new(0.0, -1.0));
+    }
+
+    #[test]
+    fn same_geometry() {
+        let a = Seg::new((0.0, 0.0), (2.0, 1.0));
+        assert!(a.same_geometry(a));
+        assert!(a.same_geometry(Seg::new((2.0, 1.0), (0.0, 0.0))));
+        assert!(!a.same_geometry(Seg::new((0.0, 0.0), (4.0, 2.0))));
+        assert!(!a.same_geometry(Seg::new((0.0, 0.0), (2.0, 2.0))));
+        let b = Line::new((0.0, 0.0), (2.0, 1.0));
+        assert!(b.same_geometry(Line::new((2.0, 1.0), (0.0, 0.0))));
+        assert!(b.same_geometry(Line::new((4.0, 2.0), (-2.0, -1.0))));
+        assert!(!b.same_geometry(Line::new((0.0, 1.0), (2.0, 2.0))));
+        assert!(!b.same_geometry(Line::new((0.0, 0.0), (0.0, 0.0))));
+        let c = Line::new((0.0, 0.0), (1.0, 3.0));
+        let d = Line::new((0.2, 0.2 * 3.0), (2.0, 6.0));
+        assert_eq!(c.same_geometry(d), d.same_geometry(c));
+    }
+
+    #[test]
+    fn seg_oriented() {
+        let a = Seg::new((0.0, 5.0), (1.0, 0.0));
+        assert!(a.is_oriented());
+        assert_eq!(a.oriented(), a);
+        let b = Seg::new((1.0, 0.0), (0.0, 5.0));
+        assert!(!b.is_oriented());
+        assert_eq!(b.oriented(), a);
+        let c = Seg::new((2.0, 3.0), (2.0, 1.0));
+        assert!(!c.is_oriented());
+        assert_eq!(c.oriented(), Seg::new((2.0, 1.0), (2.0, 3.0)));
+        assert!(Seg::new((2.0, 3.0), (2.0, 3.0)).is_oriented());
+    }
+
+    #[test]
+    fn seg_degenerate() {
+        assert!(!Seg::new((0.0, 0.0), (1.0, 0.0)).is_degenerate(1e-6));
+        assert!(Seg::new((1.0, 2.0), (1.0, 2.0)).is_degenerate(1e-6));
+        assert!(Seg::new((1.0, 2.0), (1.0, 2.0000001)).is_degenerate(1e-6));
+        assert!(!Seg::new((1.0, 2.0), (1.0, 2.0)).is_degenerate(0.0));
+    }
+
+    #[test]
+    fn seg_normal() {
+        let a = Seg::new((0.0, 0.0), (5.0, 0.0));
+        assert_eq!(a.normal(), Pt::new(0.0, 1.0));
+        let b = Seg::new((2.0, 3.0), (2.0, -1.0));
+        assert_eq!(b.normal(), Pt::new(1.0, 0.0));
+        let c = Seg::new((2.0, 3.0), (2.0, 3.0));
+        assert_eq!(c.normal(), Pt::default());
+    }
+
+    #[test]
+    fn miter_point() {
+        let a = Seg::new((0.0, 0.0), (2.0, 0.0));
+        let b = Seg::new((2.0, 0.0), (2.0, 2.0));
+        assert_eq!(a.miter_point(b, 1.0), Some(Pt::new(1.0, 1.0)));
+        assert_eq!(a.miter_point(b, -1.0), Some(Pt::new(3.0, -1.0)));
+        let a = Seg::new((0.0, 0.0), (4.0, 0.0));
+        let b = Seg::new((4.0, 0.0), (0.0, 1.0));
+        let m = a.miter_point(b, -1.0).unwrap();
+        assert_approx_eq!(m.x, 8.0 + 17.0f64.sqrt());
+        assert_approx_eq!(m.y, -1.0);
+        assert!(m.distance((4.0, 0.0)) > 8.0);
+        let c = Seg::new((4.0, 0.0), (6.0, 0.0));
+        assert_eq!(a.miter_point(c, 1.0), None);
+    }
+
     #[test]
     fn seg_intersection() {
         let a = Seg::new((0.0, 0.0), (1.0, 0.0));
-        assert_eq!(a.intersection(a), None);
+        assert_eq!(a.intersection(a), Some(Pt::new(0.0, 0.0)));
         let b = Seg::new((1.0, 1.0), (1.0, 0.0));
         assert_eq!(a.intersection(b), Some(Pt::new(1.0, 0.0)));
         let c = Seg::new((0.5, 1.0), (0.5, 10.0));
         assert_eq!(a.intersection(c), None);
         let d = Seg::new((0.5, 1.0), (0.5, -1.0));
         assert_eq!(a.intersection(d), Some(Pt::new(0.5, 0.0)));
+        let e = Seg::new((5.0, -1.0), (5.0, 1.0));
+        assert_eq!(a.intersection(e), None);
+        assert_eq!(e.intersection(a), None);
+        let f = Seg::new((2.0, 0.0), (3.0, 0.0));
+        assert_eq!(a.intersection(f), None);
+        let g = Seg::new((0.5, 0.0), (3.0, 0.0));
+        assert_eq!(a.intersection(g), Some(Pt::new(1.0, 0.0)));
+    }
+
+    #[test]
+    fn seg_split() {
+        let a = Seg::new((0.0, 0.0), (2.0, 2.0));
+        let b = Seg::new((0.0, 2.0), (2.0, 0.0));
+        assert_eq!(
+            a.split_at_intersection(b),
+            [
+                Seg::new((0.0, 0.0), (1.0, 1.0)),
+                Seg::new((1.0, 1.0), (2.0, 2.0))
+            ]
+        );
+        let c = Seg::new((1.0, 1.0), (2.0, 0.0));
+        assert_eq!(
+            a.split_at_intersection(c),
+            [
+                Seg::new((0.0, 0.0), (1.0, 1.0)),
+                Seg::new((1.0, 1.0), (2.0, 2.0))
+            ]
+        );
+        assert_eq!(c.split_at_intersection(a), [c]);
+        let d = Seg::new((3.0, 0.0), (4.0, 0.0));
+        assert_eq!(a.split_at_intersection(d), [a]);
+    }
+
+    #[test]
+    fn seg_touching() {
+        let a = Seg::new((0.1, 0.3), (0.7, 0.9));
+        let b = Seg::new((0.7, 0.9), (1.3, 0.2));
+        assert_eq!(a.intersection(b), Some(Pt::new(0.7, 0.9)));
+        assert_eq!(b.intersection(a), Some(Pt::new(0.7, 0.9)));
+        let c = Seg::new((1.3, 0.2), (0.7, 0.9));
+        assert_eq!(a.intersection(c), Some(Pt::new(0.7, 0.9)));
+        assert_eq!(c.intersection(a), Some(Pt::new(0.7, 0.9)));
+        let d = Seg::new((0.0, 0.0), (2.0, 0.0));
+        let e = Seg::new((1.0, 0.0), (1.0, 3.0));
+        assert_eq!(d.intersection(e), Some(Pt::new(1.0, 0.0)));
+        assert_eq!(e.intersection(d), Some(Pt::new(1.0, 0.0)));
+        assert!(d.intersects(e));
+        assert!(e.intersects(d));
+    }
+
+    #[test]
+    fn seg_bisector() {
+        let a = Seg::new((0.0f64, 0.0), (1.0, 0.0));
+        let b = Seg::new((1.0, 0.0), (1.0, 1.0));
+        let v = a.bisector_direction(b);
+        assert_approx_eq!(v.x, -0.5f64.sqrt());
+        assert_approx_eq!(v.y, 0.5f64.sqrt());
+        assert_approx_eq!(
+            v.angle_rel(b.p1 - b.p0),
+            std::f64::consts::FRAC_PI_4
+        );
+        let c = Seg::new((1.0, 0.0), (3.0, 0.0));
+        assert_eq!(a.bisector_direction(c), Pt::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn seg_fraction() {
+        let a = Seg::new((0.0, 0.0), (4.0, 2.0));
+        assert_eq!(a.fraction_at((2.0, 1.0)), 0.5);
+        assert_eq!(a.fraction_at((1.0, 3.0)), 0.5);
+        assert_eq!(a.fraction_at((-3.0, 0.0)), 0.0);
+        assert_eq!(a.fraction_at((8.0, 5.0)), 1.0);
+        let b = Seg::new((1.0, 1.0), (1.0, 1.0));
+        assert_eq!(b.fraction_at((3.0, 3.0)), 0.0);
+    }
+
+    #[test]
+    fn seg_interpolate() {
+        let a = Seg::new((0.0, 0.0), (4.0, 2.0));
+        let all = [
+            Pt::new(0.0, 0.0),
+            Pt::new(1.0, 0.5),
+            Pt::new(2.0, 1.0),
+            Pt::new(3.0, 1.5),
+            Pt::new(4.0, 2.0),
+        ];
+        assert_eq!(a.interpolate(4, true, true), all);
+        assert_eq!(a.interpolate(4, true, false), all[..4]);
+        assert_eq!(a.interpolate(4, false, true), all[1..]);
+        assert_eq!(a.interpolate(4, false, false), all[1..4]);
+        assert_eq!(a.interpolate(0, true, true), [all[0], all[4]]);
+        assert!(a.interpolate(1, false, false).is_empty());
+    }
+
+    #[test]
+    fn seg_lerp() {
+        let a = Seg::new((0.0, 0.0), (4.0, 0.0));
+        let b = Seg::new((2.0, -2.0), (2.0, 2.0));
+        assert_eq!(a.lerp(b, 0.0), a);
+        assert_eq!(a.lerp(b, 1.0), b);
+        assert_eq!(a.lerp(b, 0.5), Seg::new((1.0, -1.0), (3.0, 1.0)));
+    }
+
+    #[test]
+    fn seg_at() {
+        let a = Seg::new((0.0, 0.0), (4.0, 2.0));
+        assert_eq!(a.y_at_x(2.0), Some(1.0));
+        assert_eq!(a.y_at_x(4.0), Some(2.0));
+        assert_eq!(a.y_at_x(5.0), None);
+        assert_eq!(a.x_at_y(0.5), Some(1.0));
+        assert_eq!(a.x_at_y(-0.5), None);
+        let b = Seg::new((1.0, 3.0), (1.0, -1.0));
+        assert_eq!(b.y_at_x(1.0), None);
+        assert_eq!(b.x_at_y(0.0), Some(1.0));
+        let c = Seg::new((3.0, 2.0), (-1.0, 2.0));
+        assert_eq!(c.y_at_x(0.0), Some(2.0));
+        assert_eq!(c.x_at_y(2.0), None);
+    }
+
+    #[test]
+    fn seg_closest_points() {
+        let a = Seg::new((0.0, 0.0), (2.0, 2.0));
+        let b = Seg::new((0.0, 2.0), (2.0, 0.0));
+        assert_eq!(a.closest_points(b), (Pt::new(1.0, 1.0), Pt::new(1.0, 1.0)));
+        let a = Seg::new((0.0, 0.0), (2.0, 0.0));
+        let b = Seg::new((1.0, 1.0), (3.0, 1.0));
+        let (p0, p1) = a.closest_points(b);
+        assert_eq!((p0, p1), (Pt::new(1.0, 0.0), Pt::new(1.0, 1.0)));
+        assert_eq!(p0.distance(p1), b.distance(p0));
+        let b = Seg::new((2.0, 1.0), (3.0, 5.0));
+        let (p0, p1) = a.closest_points(b);
+        assert_eq!((p0, p1), (Pt::new(2.0, 0.0), Pt::new(2.0, 1.0)));
+        assert_eq!(p0.distance(p1), b.distance(a.p1));
+        let b = Seg::new((1.0, 3.0), (1.0, 2.0));
+        assert_eq!(a.closest_points(b), (Pt::new(1.0, 0.0), Pt::new(1.0, 2.0)));
+        let b = Seg::new((4.0, 1.0), (4.0, 1.0));
+        assert_eq!(a.closest_points(b), (Pt::new(2.0, 0.0), Pt::new(4.0, 1.0)));
+    }
+
+    #[test]
+    fn seg_intersects_robust() {
+        let a = Seg::new((0.0, 0.0), (2.0, 2.0));
+        assert!(a.intersects_robust(Seg::new((0.0, 2.0), (2.0, 0.0))));
+        assert!(!a.intersects_robust(Seg::new((1.0, 0.0), (3.0, 2.0))));
+        // shared endpoints
+        assert!(a.intersects_robust(Seg::new((2.0, 2.0), (3.0, 0.0))));
+        assert!(a.intersects_robust(Seg::new((3.0, 0.0), (0.0, 0.0))));
+        // collinear overlap
+        assert!(a.intersects_robust(Seg::new((1.0, 1.0), (3.0, 3.0))));
+        assert!(a.intersects_robust(Seg::new((3.0, 3.0), (-1.0, -1.0))));
+        assert!(!a.intersects_robust(Seg::new((3.0, 3.0), (4.0, 4.0))));
+        // T-junctions
+        assert!(a.intersects_robust(Seg::new((1.0, 1.0), (2.0, 0.0))));
+        assert!(Seg::new((1.0, 1.0), (2.0, 0.0)).intersects_robust(a));
+        assert!(!a.intersects_robust(Seg::new((1.5, 1.0), (2.0, 0.0))));
+        // nearly parallel
+        let b = Seg::new((0.0, 0.0), (1e8, 1.0));
+        assert!(!b.intersects_robust(Seg::new((0.0, 1.0), (1e8, 2.0))));
+    }
+
+    #[test]
+    fn seg_clip_convex() {
+        let tri = [Pt::new(0.0, 0.0), Pt::new(4.0, 0.0), Pt::new(0.0, 4.0)];
+        let a = Seg::new((-1.0, 1.0), (5.0, 1.0));
+        assert_eq!(a.clip_convex(&tri), Some(Seg::new((0.0, 1.0), (3.0, 1.0))));
+        let b = Seg::new((1.0, 1.0), (2.0, 1.0));
+        assert_eq!(b.clip_convex(&tri), Some(b));
+        let c = Seg::new((3.0, 3.0), (5.0, 5.0));
+        assert_eq!(c.clip_convex(&tri), None);
+        let d = Seg::new((-1.0, 5.0), (5.0, 5.0));
+        assert_eq!(d.clip_convex(&tri), None);
+        let pent = [
+            Pt::new(0.0, 0.0),
+            Pt::new(2.0, 0.0),
+            Pt::new(3.0, 2.0),
+            Pt::new(1.0, 3.0),
+            Pt::new(-1.0, 2.0),
+        ];
+        let e = Seg::new((-2.0, 1.0), (4.0, 1.0));
+        assert_eq!(
+            e.clip_convex(&pent),
+            Some(Seg::new((-0.5, 1.0), (2.5, 1.0)))
+        );
+        let f = Seg::new((1.0, -1.0), (1.0, 4.0));
+        assert_eq!(
+            f.clip_convex(&pent),
+            Some(Seg::new((1.0, 0.0), (1.0, 3.0)))
+        );
+    }
+
+    #[test]
+    fn touches_corner() {
+        let b = BBox::new([(0.1, 0.1), (0.7, 0.7)]);
+        let a = Line::new((1.3, 0.1), (0.1, 1.3));
+        assert_eq!(a.touches_corner(b, 1e-9), Some(Pt::new(0.7, 0.7)));
+        let a = Line::new((-0.7, 0.9), (0.3, -0.1));
+        assert_eq!(a.touches_corner(b, 1e-9), Some(Pt::new(0.1, 0.1)));
+        let a = Line::new((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(a.touches_corner(b, 1e-9), None);
+        let a = Line::new((0.0, 0.1), (1.0, 0.1));
+        assert_eq!(a.touches_corner(b, 1e-9), None);
+        let a = Line::new((0.0, 0.5), (0.5, 0.0));
+        assert_eq!(a.touches_corner(b, 1e-9), None);
+    }
+
+    #[test]
+    fn seg_clip_corner() {
+        let b = BBox::new([(0.1, 0.1), (0.7, 0.7)]);
+        let c0 = Pt::new(0.1, 0.1);
+        let c1 = Pt::new(0.7, 0.7);
+        let s = Seg::new((-0.7, 0.9), (0.3, -0.1));
+        assert_eq!(s.clip(b), Some(Seg::new(c0, c0)));
+        let s = Seg::new((1.3, 0.1), (0.1, 1.3));
+        assert_eq!(s.clip(b), Some(Seg::new(c1, c1)));
+        let s = Seg::new((0.9, 0.5), (0.5, 0.9));
+        assert_eq!(s.clip(b), Some(Seg::new(c1, c1)));
+        let s = Seg::new((1.3, 0.1), (0.9, 0.5));
+        assert_eq!(s.clip(b), None);
+        let s = Seg::new((0.0, 0.0), (1.0, 1.0));
+        assert_eq!(s.clip(b), Some(Seg::new(c0, c1)));
+        let b = BBox::new([(1e6 + 0.1, 1e6 + 0.1), (1e6 + 0.7, 1e6 + 0.7)]);
+        let c0 = Pt::new(1e6 + 0.1, 1e6 + 0.1);
+        let s = Seg::new((1e6 - 0.7, 1e6 + 0.9), (1e6 + 0.3, 1e6 - 0.1));
+        assert_eq!(s.clip(b), Some(Seg::new(c0, c0)));
+    }
+
+    #[test]
+    fn bbox_include_seg() {
+        let mut b = BBox::new([(0.0, 0.0), (1.0, 1.0)]);
+        b.include_seg(Seg::new((0.5, 0.5), (3.0, -2.0)));
+        assert_eq!(b, BBox::new([(0.0, -2.0), (3.0, 1.0)]));
+        b.include(Seg::new((-1.0, 4.0), (0.0, 0.0)));
+        assert_eq!(b, BBox::new([(-1.0, -2.0), (3.0, 4.0)]));
+        assert_eq!(
+            BBox::from(Seg::new((2.0, 0.0), (0.0, 3.0))),
+            BBox::new([(0.0, 0.0), (2.0, 3.0)])
+        );
+    }
+
+    #[test]
+    fn bbox_contains_seg() {
+        let b = BBox::new([(0.0, 0.0), (4.0, 4.0)]);
+        assert!(b.contains_seg(Seg::new((1.0, 1.0), (3.0, 2.0))));
+        assert!(b.contains_seg(Seg::new((0.0, 0.0), (4.0, 4.0))));
+        assert!(!b.contains_seg(Seg::new((1.0, 1.0), (5.0, 2.0))));
+        assert!(!b.contains_seg(Seg::new((-1.0, 2.0), (5.0, 2.0))));
+        assert!(!b.contains_seg(Seg::new((5.0, 5.0), (6.0, 7.0))));
+    }
+
+    #[test]
+    fn bbox_edges() {
+        let b = BBox::new([(1.0, 2.0), (4.0, 3.0)]);
+        let edges = b.edges();
+        assert_eq!(edges[0], Seg::new((1.0, 2.0), (4.0, 2.0)));
+        assert_eq!(edges[2], Seg::new((4.0, 3.0), (1.0, 3.0)));
+        for i in 0..4 {
+            assert_eq!(edges[i].p1, edges[(i + 1) % 4].p0);
+        }
+        assert_eq!(edges[0], b.y_min_edge());
+        assert_eq!(edges[1], b.x_max_edge());
     }
 
     #[test]
@@ -3,9 +3,12 @@
 // Copyright (c) 2020-2023  Douglas P Lau
 //
 use crate::float::Float;
+use crate::line::{Line, Seg};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::Debug;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Div, Mul, Neg, Sub};
 
 /// 2-dimensional point / vector
@@ -28,6 +31,73 @@ where
     pub y: F,
 }
 
+/// Point wrapper which can be hashed
+///
+/// Points are compared by bit pattern, except that negative zero equals
+/// zero, and all NaN values are equal.
+///
+/// ```rust
+/// use pointy::{HashPt, Pt};
+/// use std::collections::HashSet;
+///
+/// let mut pts = HashSet::new();
+/// pts.insert(HashPt(Pt::new(10.0, 15.0)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct HashPt<F>(pub Pt<F>)
+where
+    F: Float;
+
+impl<F> HashPt<F>
+where
+    F: Float,
+{
+    /// Get canonical key for comparison / hashing
+    fn key(self) -> [(u64, i16, i8); 2] {
+        let canonical = |v: F| {
+            if v.is_nan() {
+                F::nan().integer_decode()
+            } else if v == F::zero() {
+                F::zero().integer_decode()
+            } else {
+                v.integer_decode()
+            }
+        };
+        [canonical(self.0.x), canonical(self.0.y)]
+    }
+}
+
+impl<F> PartialEq for HashPt<F>
+where
+    F: Float,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.key() == rhs.key()
+    }
+}
+
+impl<F> Eq for HashPt<F> where F: Float {}
+
+impl<F> Hash for HashPt<F>
+where
+    F: Float,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+/// Rounding mode for snapping components
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundMode {
+    /// Round to nearest value (half away from zero)
+    Nearest,
+    /// Round down (toward negative infinity)
+    Down,
+    /// Round up (toward positive infinity)
+    Up,
+}
+
 impl<F> From<&Pt<F>> for Pt<F>
 where
     F: Float,
@@ -137,9 +207,9 @@ where
 
     /// Get cross product with another vector.
     ///
-    /// Returns the signed magnitude of the 3D cross product.
+    /// This delegates to [perp_dot](Pt::perp_dot).
     fn mul(self, rhs: Self) -> F {
-        self.x * rhs.y - self.y * rhs.x
+        self.perp_dot(rhs)
     }
 }
 
@@ -151,9 +221,9 @@ where
 
     /// Get cross product with another vector.
     ///
-    /// Returns the signed magnitude of the 3D cross product.
+    /// This delegates to [perp_dot](Pt::perp_dot).
     fn mul(self, rhs: (F, F)) -> F {
-        self * Self::from(rhs)
+        self.perp_dot(rhs)
     }
 }
 
@@ -194,6 +264,19 @@ where
         Self { x, y }
     }
 
+    /// Parse a point from whitespace-separated coordinates.
+    ///
+    /// Returns `None` unless there are exactly two valid numbers.
+    pub fn parse_ws(s: &str) -> Option<Self> {
+        let mut tokens = s.split_whitespace();
+        let x = F::from_str_radix(tokens.next()?, 10).ok()?;
+        let y = F::from_str_radix(tokens.next()?, 10).ok()?;
+        match tokens.next() {
+            Some(_) => None,
+            None => Some(Self { x, y }),
+        }
+    }
+
     /// Create a unit vector from an angle (radians)
     pub fn from_angle(angle: F) -> Self {
         Self {
@@ -202,6 +285,55 @@ where
         }
     }
 
+    /// Create a point from polar coordinates.
+    ///
+    /// * `radius` Distance from origin.
+    /// * `angle` Angle from positive X axis (radians).
+    pub fn from_polar(radius: F, angle: F) -> Self {
+        Self {
+            x: radius * angle.cos(),
+            y: radius * angle.sin(),
+        }
+    }
+
+    /// Convert to polar coordinates.
+    ///
+    /// Returns the radius and angle (radians).  The angle of a zero vector
+    /// is 0.
+    pub fn to_polar(self) -> (F, F) {
+        let radius = self.mag();
+        if radius > F::zero() {
+            (radius, self.angle())
+        } else {
+            (F::zero(), F::zero())
+        }
+    }
+
+    /// Offset by a distance in the direction of an angle (radians)
+    pub fn polar_offset(self, distance: F, angle: F) -> Self {
+        self + Self::from_polar(distance, angle)
+    }
+
+    /// Get the components as an array
+    pub fn as_array(self) -> [F; 2] {
+        [self.x, self.y]
+    }
+
+    /// Get the components as a tuple
+    pub fn as_tuple(self) -> (F, F) {
+        (self.x, self.y)
+    }
+
+    /// Create a point with a different X value
+    pub fn with_x(self, x: F) -> Self {
+        Self { x, y: self.y }
+    }
+
+    /// Create a point with a different Y value
+    pub fn with_y(self, y: F) -> Self {
+        Self { x: self.x, y }
+    }
+
     /// Create a point with minimum component values of two points
     pub fn with_min<P: Into<Self>>(self, rhs: P) -> Self {
         let rhs = rhs.into();
@@ -218,6 +350,77 @@ where
         Self { x, y }
     }
 
+    /// Get the component-wise minimum of two vectors
+    ///
+    /// This is equivalent to [with_min](Self::with_min).
+    pub fn component_min<P: Into<Self>>(self, rhs: P) -> Self {
+        self.with_min(rhs)
+    }
+
+    /// Get the component-wise maximum of two vectors
+    ///
+    /// This is equivalent to [with_max](Self::with_max).
+    pub fn component_max<P: Into<Self>>(self, rhs: P) -> Self {
+        self.with_max(rhs)
+    }
+
+    /// Clamp each component between two bound points.
+    ///
+    /// * `min` Point with minimum component values.
+    /// * `max` Point with maximum component values.
+    pub fn clamp_components<P: Into<Self>>(self, min: P, max: P) -> Self {
+        self.with_max(min).with_min(max)
+    }
+
+    /// Get the smaller of the two components
+    pub fn min_component(self) -> F {
+        self.x.min(self.y)
+    }
+
+    /// Get the larger of the two components
+    pub fn max_component(self) -> F {
+        self.x.max(self.y)
+    }
+
+    /// Get the axis with the largest magnitude (0 for X, 1 for Y).
+    ///
+    /// If both magnitudes are equal, X is chosen.
+    pub fn longest_axis(self) -> usize {
+        if self.y.abs() > self.x.abs() {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Get the absolute difference of each component
+    pub fn abs_diff<P: Into<Self>>(self, rhs: P) -> Self {
+        let rhs = rhs.into();
+        let x = (self.x - rhs.x).abs();
+        let y = (self.y - rhs.y).abs();
+        Self { x, y }
+    }
+
+    /// Round components to multiples of a step.
+    ///
+    /// * `step` Step size.
+    /// * `mode` Rounding mode.
+    pub fn round_to(self, step: F, mode: RoundMode) -> Self {
+        let round = |v: F| {
+            let v = v / step;
+            let v = match mode {
+                RoundMode::Nearest => v.round(),
+                RoundMode::Down => v.floor(),
+                RoundMode::Up => v.ceil(),
+            };
+            v * step
+        };
+        Self {
+            x: round(self.x),
+            y: round(self.y),
+        }
+    }
+
     /// Get the magnitude (length) of a vector
     pub fn mag(self) -> F {
         self.x.hypot(self.y)
@@ -233,6 +436,18 @@ where
         }
     }
 
+    /// Normalize to unit length vector, with a fallback.
+    ///
+    /// If the magnitude is zero, `fallback` is returned instead.
+    pub fn normalize_or<P: Into<Self>>(self, fallback: P) -> Self {
+        let m = self.mag();
+        if m > F::zero() {
+            self / m
+        } else {
+            fallback.into()
+        }
+    }
+
     /// Get distance squared to another point
     pub fn distance_sq<P: Into<Self>>(self, rhs: P) -> F {
         let v = self - rhs.into();
@@ -244,6 +459,27 @@ where
         (self - rhs.into()).mag()
     }
 
+    /// Get distance to a line segment
+    pub fn distance_to_segment(self, seg: Seg<F>) -> F {
+        seg.distance(self)
+    }
+
+    /// Get distance to a line
+    pub fn distance_to_line(self, line: Line<F>) -> F {
+        line.distance(self)
+    }
+
+    /// Reflect across the line containing a segment
+    pub fn reflect_across(self, seg: Seg<F>) -> Self {
+        Line::new(seg.p0, seg.p1).reflect(self)
+    }
+
+    /// Get the nearest point on a line, and the distance to it
+    pub fn nearest_on_line(self, line: Line<F>) -> (Self, F) {
+        let (foot, dist) = line.foot(self);
+        (foot, dist.abs())
+    }
+
     /// Get the midpoint from this to another point
     pub fn midpoint<P: Into<Self>>(self, rhs: P) -> Self {
         let two = F::one() + F::one();
@@ -279,17 +515,201 @@ where
         }
     }
 
+    /// Reflect across a line through the origin.
+    ///
+    /// Returns a zero vector if `axis` is zero.
+    ///
+    /// * `axis` Direction of line to reflect across.
+    pub fn reflect<P: Into<Self>>(self, axis: P) -> Self {
+        let axis = axis.into();
+        let len_sq = axis.dot(axis);
+        if len_sq > F::zero() {
+            let two = F::one() + F::one();
+            axis * (two * self.dot(axis) / len_sq) - self
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Get perpendicular vector on the side of a reference vector.
+    ///
+    /// Returns [left](Self::left) if its dot product with `reference` is
+    /// non-negative, otherwise [right](Self::right).
+    pub fn perpendicular_toward<P: Into<Self>>(self, reference: P) -> Self {
+        let left = self.left();
+        if left.dot(reference) >= F::zero() {
+            left
+        } else {
+            self.right()
+        }
+    }
+
+    /// Get the perpendicular dot product with another vector.
+    ///
+    /// This is the dot product of `self.left()` with `rhs`, which is
+    /// positive if `rhs` is counter-clockwise from `self`.
+    pub fn perp_dot<P: Into<Self>>(self, rhs: P) -> F {
+        let rhs = rhs.into();
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Get cross product with another vector.
+    ///
+    /// Returns the signed magnitude of the 3D cross product.  This is the
+    /// same as [perp_dot](Self::perp_dot), or the `*` operator between two
+    /// vectors.
+    pub fn cross<P: Into<Self>>(self, rhs: P) -> F {
+        self.perp_dot(rhs)
+    }
+
+    /// Get the sign of the cross product with another vector.
+    ///
+    /// Returns 1 if `rhs` is counter-clockwise from `self`, -1 if
+    /// clockwise, or 0 if they are collinear.
+    pub fn cross_sign<P: Into<Self>>(self, rhs: P) -> i8 {
+        let c = self.cross(rhs);
+        if c > F::zero() {
+            1
+        } else if c < F::zero() {
+            -1
+        } else {
+            0
+        }
+    }
+
+    /// Get cross product with a scalar.
+    ///
+    /// Returns a perpendicular vector scaled by `s` (right-hand for
+    /// positive `s`).
+    pub fn cross_scalar(self, s: F) -> Self {
+        Self {
+            x: self.y * s,
+            y: -self.x * s,
+        }
+    }
+
     /// Get dot product with another vector
     pub fn dot<P: Into<Self>>(self, rhs: P) -> F {
         let rhs = rhs.into();
         self.x * rhs.x + self.y * rhs.y
     }
 
+    /// Project onto another vector.
+    ///
+    /// Projecting onto a zero vector results in a zero vector.
+    pub fn project_onto<P: Into<Self>>(self, dir: P) -> Self {
+        let dir = dir.into();
+        let len_sq = dir.dot(dir);
+        if len_sq > F::zero() {
+            dir * (self.dot(dir) / len_sq)
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Get the component perpendicular to another vector
+    pub fn perpendicular_component<P: Into<Self>>(self, dir: P) -> Self {
+        self - self.project_onto(dir)
+    }
+
+    /// Refract a vector through a surface (Snell's law).
+    ///
+    /// The vector must be a unit incident direction; otherwise the result
+    /// is incorrect, and may be a false total internal reflection.
+    /// Returns `None` on total internal reflection.
+    ///
+    /// * `normal` Unit surface normal, opposing the incident direction.
+    /// * `eta` Ratio of refractive indices (incident / transmitted).
+    pub fn refract<P: Into<Self>>(self, normal: P, eta: F) -> Option<Self> {
+        let normal = normal.into();
+        let d = normal.dot(self);
+        let k = F::one() - eta * eta * (F::one() - d * d);
+        if k < F::zero() {
+            None
+        } else {
+            Some(self * eta - normal * (eta * d + k.sqrt()))
+        }
+    }
+
     /// Get vector angle in radians
     pub fn angle(self) -> F {
         self.y.atan2(self.x)
     }
 
+    /// Get vector angle in radians, from 0 up to (not including) 2PI
+    pub fn angle_2pi(self) -> F {
+        let th = self.angle();
+        if th >= F::zero() {
+            th
+        } else {
+            let th = th + F::TAU();
+            // very small negative angles can round up to 2PI
+            if th < F::TAU() {
+                th
+            } else {
+                F::zero()
+            }
+        }
+    }
+
+    /// Rotate toward the direction of another vector.
+    ///
+    /// The rotation is limited to `max_angle` (radians), and the magnitude
+    /// is preserved.  If the target direction is within `max_angle`, the
+    /// result has the same direction as the target.
+    pub fn rotate_toward<P: Into<Self>>(self, target: P, max_angle: F) -> Self {
+        let target = target.into();
+        let m = self.mag();
+        let th = target.angle_rel(self);
+        if th.abs() <= max_angle {
+            target.normalize() * m
+        } else {
+            Self::from_angle(self.angle() + max_angle * th.signum()) * m
+        }
+    }
+
+    /// Rotate by an angle (radians)
+    pub fn rotate(self, angle: F) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        self.rotate_cs(sin, cos)
+    }
+
+    /// Rotate around a center point by an angle (radians)
+    pub fn rotate_around<P: Into<Self>>(self, center: P, angle: F) -> Self {
+        let center = center.into();
+        (self - center).rotate(angle) + center
+    }
+
+    /// Rotate using precomputed sine and cosine of an angle.
+    ///
+    /// This avoids recalculating them when rotating many points by the
+    /// same angle.
+    ///
+    /// * `sin` Sine of rotation angle.
+    /// * `cos` Cosine of rotation angle.
+    pub fn rotate_cs(self, sin: F, cos: F) -> Self {
+        let x = self.x * cos - self.y * sin;
+        let y = self.x * sin + self.y * cos;
+        Self { x, y }
+    }
+
+    /// Snap direction to the nearest of evenly spaced directions.
+    ///
+    /// The directions start at angle 0 (positive X axis).  Returns a unit
+    /// vector, or a zero vector if `self` is zero or `divisions` is 0.
+    pub fn snap_direction(self, divisions: usize) -> Self {
+        if divisions == 0 || self.mag() == F::zero() {
+            return Self::default();
+        }
+        let step = F::TAU() / F::from(divisions).unwrap();
+        Self::from_angle((self.angle() / step).round() * step)
+    }
+
+    /// Get angle of the direction toward another point (radians)
+    pub fn angle_to<P: Into<Self>>(self, rhs: P) -> F {
+        (rhs.into() - self).angle()
+    }
+
     /// Get relative angle to another vector.
     ///
     /// The result will be between `-PI` and `+PI`.
@@ -306,6 +726,188 @@ where
     }
 }
 
+/// Remove consecutive points which are nearly coincident.
+///
+/// Each point closer than `epsilon` to the previous kept point is removed.
+/// The order of the remaining points is preserved.  For closed rings, the
+/// last point is not compared with the first.
+pub fn dedup_points<F: Float>(pts: &[Pt<F>], epsilon: F) -> Vec<Pt<F>> {
+    let mut out: Vec<Pt<F>> = Vec::with_capacity(pts.len());
+    for pt in pts {
+        match out.last() {
+            Some(prev) if prev.distance(*pt) < epsilon => (),
+            _ => out.push(*pt),
+        }
+    }
+    out
+}
+
+/// Get the centroid (arithmetic mean) of a set of points.
+///
+/// Returns `None` if `pts` is empty.
+pub fn centroid<F: Float>(pts: &[Pt<F>]) -> Option<Pt<F>> {
+    if pts.is_empty() {
+        return None;
+    }
+    let sum = pts.iter().fold(Pt::default(), |acc, pt| acc + *pt);
+    Some(sum / F::from(pts.len()).unwrap())
+}
+
+/// Calculate bilinear interpolation of four corner points.
+///
+/// Interpolates first along `u` (from `p00` toward `p10`, and `p01`
+/// toward `p11`), then along `v` between those results.
+pub fn bilerp<F: Float>(
+    p00: Pt<F>,
+    p10: Pt<F>,
+    p01: Pt<F>,
+    p11: Pt<F>,
+    u: F,
+    v: F,
+) -> Pt<F> {
+    let p0 = p00 + (p10 - p00) * u;
+    let p1 = p01 + (p11 - p01) * u;
+    p0 + (p1 - p0) * v
+}
+
+/// Sort points by angle around a center point.
+///
+/// Points are sorted counter-clockwise by [angle_2pi](Pt::angle_2pi),
+/// starting from the positive X axis.  Points at the same angle are
+/// sorted by distance from `center`.
+pub fn sort_by_angle<F: Float>(pts: &mut [Pt<F>], center: Pt<F>) {
+    pts.sort_by(|a, b| {
+        let (a, b) = (*a - center, *b - center);
+        a.angle_2pi()
+            .partial_cmp(&b.angle_2pi())
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| {
+                let (da, db) = (a.dot(a), b.dot(b));
+                da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+            })
+    });
+}
+
+impl Pt<f32> {
+    /// Get little-endian byte representation (X then Y)
+    pub fn to_le_bytes(self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        bytes[..4].copy_from_slice(&self.x.to_le_bytes());
+        bytes[4..].copy_from_slice(&self.y.to_le_bytes());
+        bytes
+    }
+
+    /// Get big-endian byte representation (X then Y)
+    pub fn to_be_bytes(self) -> [u8; 8] {
+        let mut bytes = [0; 8];
+        bytes[..4].copy_from_slice(&self.x.to_be_bytes());
+        bytes[4..].copy_from_slice(&self.y.to_be_bytes());
+        bytes
+    }
+
+    /// Create a point from little-endian bytes (X then Y)
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        let mut x = [0; 4];
+        let mut y = [0; 4];
+        x.copy_from_slice(&bytes[..4]);
+        y.copy_from_slice(&bytes[4..]);
+        Self {
+            x: f32::from_le_bytes(x),
+            y: f32::from_le_bytes(y),
+        }
+    }
+
+    /// Create a point from big-endian bytes (X then Y)
+    pub fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        let mut x = [0; 4];
+        let mut y = [0; 4];
+        x.copy_from_slice(&bytes[..4]);
+        y.copy_from_slice(&bytes[4..]);
+        Self {
+            x: f32::from_be_bytes(x),
+            y: f32::from_be_bytes(y),
+        }
+    }
+}
+
+impl Pt<f64> {
+    /// Get little-endian byte representation (X then Y)
+    pub fn to_le_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.x.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.y.to_le_bytes());
+        bytes
+    }
+
+    /// Get big-endian byte representation (X then Y)
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.x.to_be_bytes());
+        bytes[8..].copy_from_slice(&self.y.to_be_bytes());
+        bytes
+    }
+
+    /// Create a point from little-endian bytes (X then Y)
+    pub fn from_le_bytes(bytes: [u8; 16]) -> Self {
+        let mut x = [0; 8];
+        let mut y = [0; 8];
+        x.copy_from_slice(&bytes[..8]);
+        y.copy_from_slice(&bytes[8..]);
+        Self {
+            x: f64::from_le_bytes(x),
+            y: f64::from_le_bytes(y),
+        }
+    }
+
+    /// Create a point from big-endian bytes (X then Y)
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let mut x = [0; 8];
+        let mut y = [0; 8];
+        x.copy_from_slice(&bytes[..8]);
+        y.copy_from_slice(&bytes[8..]);
+        Self {
+            x: f64::from_be_bytes(x),
+            y: f64::from_be_bytes(y),
+        }
+    }
+}
+
+impl From<(i32, i32)> for Pt<f32> {
+    fn from(pt: (i32, i32)) -> Self {
+        Self {
+            x: pt.0 as f32,
+            y: pt.1 as f32,
+        }
+    }
+}
+
+impl From<(i32, i32)> for Pt<f64> {
+    fn from(pt: (i32, i32)) -> Self {
+        Self {
+            x: pt.0.into(),
+            y: pt.1.into(),
+        }
+    }
+}
+
+impl From<(u32, u32)> for Pt<f32> {
+    fn from(pt: (u32, u32)) -> Self {
+        Self {
+            x: pt.0 as f32,
+            y: pt.1 as f32,
+        }
+    }
+}
+
+impl From<(u32, u32)> for Pt<f64> {
+    fn from(pt: (u32, u32)) -> Self {
+        Self {
+            x: pt.0.into(),
+            y: pt.1.into(),
+        }
+    }
+}
+
 impl From<Pt<f32>> for Pt<f64> {
     fn from(pt: Pt<f32>) -> Self {
         Self {
@@ -338,6 +940,498 @@ mod test {
         assert_eq!(a.right(), Pt::new(1.0, -2.0));
     }
 
+    #[test]
+    fn normalize_or() {
+        let a = Pt::new(3.0f32, 4.0);
+        assert_eq!(a.normalize_or((1.0, 0.0)), Pt::new(0.6, 0.8));
+        let b = Pt::new(0.0f32, 0.0);
+        assert_eq!(b.normalize_or((0.0, -1.0)), Pt::new(0.0, -1.0));
+        assert_eq!(b.normalize(), Pt::default());
+    }
+
+    #[test]
+    fn distance_to() {
+        let s = Seg::new((0.0, 0.0), (4.0, 0.0));
+        let l = Line::new((0.0, 0.0), (4.0, 0.0));
+        for p in [Pt::new(2.0, 3.0), Pt::new(7.0, -4.0), Pt::new(-1.0, 0.0)] {
+            assert_eq!(p.distance_to_segment(s), s.distance(p));
+            assert_eq!(p.distance_to_line(l), l.distance(p));
+        }
+        assert_eq!(Pt::new(7.0, -4.0).distance_to_segment(s), 5.0);
+        assert_eq!(Pt::new(7.0, -4.0).distance_to_line(l), 4.0);
+    }
+
+    #[test]
+    fn reflect_across() {
+        let s = Seg::new((0.0f64, 0.0), (2.0, 2.0));
+        let r = Pt::new(3.0, 1.0).reflect_across(s);
+        assert_approx_eq!(r.x, 1.0);
+        assert_approx_eq!(r.y, 3.0);
+        let r = Pt::new(5.0, 0.0).reflect_across(s);
+        assert_approx_eq!(r.x, 0.0);
+        assert_approx_eq!(r.y, 5.0);
+        let s = Seg::new((0.0f64, 2.0), (2.0, 0.0));
+        let r = Pt::new(0.0, 0.0).reflect_across(s);
+        assert_approx_eq!(r.x, 2.0);
+        assert_approx_eq!(r.y, 2.0);
+        let r = Pt::new(1.0, 1.0).reflect_across(s);
+        assert_approx_eq!(r.x, 1.0);
+        assert_approx_eq!(r.y, 1.0);
+    }
+
+    #[test]
+    fn nearest_on_line() {
+        let line = Line::new((0.0f64, 0.0), (10.0, 0.0));
+        let (p, d) = Pt::new(5.0, -5.0).nearest_on_line(line);
+        assert_eq!(p, Pt::new(5.0, 0.0));
+        assert_eq!(d, 5.0);
+        let line = Line::new((1.0f64, 1.0), (4.0, 5.0));
+        for pt in [Pt::new(0.0, 0.0), Pt::new(3.0, -2.0), Pt::new(-1.0, 7.0)] {
+            let (p, d) = pt.nearest_on_line(line);
+            let q = line.project(pt);
+            assert_approx_eq!(p.x, q.x);
+            assert_approx_eq!(p.y, q.y);
+            assert_approx_eq!(d, line.distance(pt));
+        }
+    }
+
+    #[test]
+    fn reflect() {
+        let p = Pt::new(3.0f64, 1.0);
+        assert_eq!(p.reflect((1.0, 0.0)), Pt::new(3.0, -1.0));
+        assert_eq!(p.reflect((0.0, 2.0)), Pt::new(-3.0, 1.0));
+        assert_eq!(p.reflect((1.0, 1.0)), Pt::new(1.0, 3.0));
+        let axis = Pt::new(2.0, -0.7);
+        let r = p.reflect(axis).reflect(axis);
+        assert_approx_eq!(r.x, p.x);
+        assert_approx_eq!(r.y, p.y);
+        assert_eq!(p.reflect((0.0, 0.0)), Pt::default());
+    }
+
+    #[test]
+    fn project() {
+        let v = Pt::new(3.0f64, 4.0);
+        let dir = Pt::new(2.0, 0.0);
+        assert_eq!(v.project_onto(dir), Pt::new(3.0, 0.0));
+        assert_eq!(v.perpendicular_component(dir), Pt::new(0.0, 4.0));
+        let dir = Pt::new(1.0, 1.0);
+        let p = v.project_onto(dir);
+        let q = v.perpendicular_component(dir);
+        assert_approx_eq!(q.dot(dir), 0.0);
+        assert_approx_eq!((p + q).x, v.x);
+        assert_approx_eq!((p + q).y, v.y);
+        assert_eq!(v.project_onto((0.0, 0.0)), Pt::default());
+        assert_eq!(v.perpendicular_component((0.0, 0.0)), v);
+    }
+
+    #[test]
+    fn refract() {
+        let n = Pt::new(0.0f64, 1.0);
+        let a = Pt::new(0.0f64, -1.0);
+        assert_eq!(a.refract(n, 1.0), Some(a));
+        let b = Pt::new(1.0f64, -1.0).normalize();
+        let r = b.refract(n, 1.0).unwrap();
+        assert_approx_eq!(r.x, b.x);
+        assert_approx_eq!(r.y, b.y);
+        let r = b.refract(n, 1.0 / 1.5).unwrap();
+        assert_approx_eq!(r.mag(), 1.0);
+        assert_approx_eq!(r.x, b.x / 1.5);
+        assert!(r.y < 0.0);
+        assert_eq!(b.refract(n, 1.5), None);
+    }
+
+    #[test]
+    fn round_to() {
+        let a = Pt::new(2.6f32, -1.3);
+        assert_eq!(a.round_to(1.0, RoundMode::Nearest), Pt::new(3.0, -1.0));
+        assert_eq!(a.round_to(1.0, RoundMode::Down), Pt::new(2.0, -2.0));
+        assert_eq!(a.round_to(1.0, RoundMode::Up), Pt::new(3.0, -1.0));
+        let b = Pt::new(7.0f32, 13.0);
+        assert_eq!(b.round_to(5.0, RoundMode::Nearest), Pt::new(5.0, 15.0));
+        assert_eq!(b.round_to(5.0, RoundMode::Down), Pt::new(5.0, 10.0));
+        assert_eq!(b.round_to(5.0, RoundMode::Up), Pt::new(10.0, 15.0));
+    }
+
+    #[test]
+    fn perpendicular_toward() {
+        let a = Pt::new(2.0f32, 1.0);
+        assert_eq!(a.perpendicular_toward((0.0, 1.0)), Pt::new(-1.0, 2.0));
+        assert_eq!(a.perpendicular_toward((0.0, -1.0)), Pt::new(1.0, -2.0));
+        assert_eq!(a.perpendicular_toward((-3.0, 1.0)), Pt::new(-1.0, 2.0));
+        assert_eq!(a.perpendicular_toward((3.0, -1.0)), Pt::new(1.0, -2.0));
+    }
+
+    #[test]
+    fn perp_dot() {
+        let a = Pt::new(2.0f32, 1.0);
+        let b = Pt::new(3.0, 4.0);
+        assert_eq!(a.perp_dot(b), 5.0);
+        assert_eq!(a.perp_dot(b), a.left().dot(b));
+        assert_eq!(a.perp_dot(b), a * b);
+        assert_eq!(a.perp_dot(b), a * (3.0, 4.0));
+        assert_eq!(b.perp_dot(a), -5.0);
+        assert_eq!(a.perp_dot((-4.0, -2.0)), 0.0);
+    }
+
+    #[test]
+    fn cross() {
+        let a = Pt::new(2.0f32, 1.0);
+        let b = Pt::new(3.0, 4.0);
+        assert_eq!(a.cross(b), 5.0);
+        assert_eq!(a.cross(b), a * b);
+        assert_eq!(b.cross(a), -5.0);
+        assert_eq!(a.cross((4.0, 2.0)), 0.0);
+    }
+
+    #[test]
+    fn cross_sign() {
+        let a = Pt::new(2.0, 1.0);
+        assert_eq!(a.cross_sign((3.0, 4.0)), 1);
+        assert_eq!(a.cross_sign((3.0, -4.0)), -1);
+        assert_eq!(a.cross_sign((4.0, 2.0)), 0);
+        assert_eq!(a.cross_sign((-2.0, -1.0)), 0);
+        assert_eq!(Pt::default().cross_sign(a), 0);
+    }
+
+    #[test]
+    fn cross_scalar() {
+        let a = Pt::new(1.0f32, 0.0);
+        assert_eq!(a.cross_scalar(2.0), Pt::new(0.0, -2.0));
+        assert_eq!(a.cross_scalar(-3.0), Pt::new(0.0, 3.0));
+        assert_eq!(
+            Pt::new(2.0, 1.0).cross_scalar(1.0),
+            Pt::new(2.0, 1.0).right()
+        );
+    }
+
+    #[test]
+    fn parse_ws() {
+        assert_eq!(Pt::parse_ws("1.5 -2"), Some(Pt::new(1.5, -2.0)));
+        assert_eq!(Pt::parse_ws("3\t4"), Some(Pt::new(3.0, 4.0)));
+        assert_eq!(Pt::parse_ws("  1e2 \t 0.25\n"), Some(Pt::new(100.0, 0.25)));
+        assert_eq!(Pt::<f32>::parse_ws("1.0"), None);
+        assert_eq!(Pt::<f32>::parse_ws("1 2 3"), None);
+        assert_eq!(Pt::<f32>::parse_ws("1,2"), None);
+        assert_eq!(Pt::<f32>::parse_ws("x 2"), None);
+    }
+
+    #[test]
+    fn as_array_tuple() {
+        let p = Pt::new(1.5, -2.0);
+        assert_eq!(p.as_array(), [1.5, -2.0]);
+        assert_eq!(p.as_tuple(), (1.5, -2.0));
+        assert_eq!(Pt::from(p.as_array()), p);
+        assert_eq!(Pt::from(p.as_tuple()), p);
+    }
+
+    #[test]
+    fn with_xy() {
+        let p = Pt::new(1.0, 2.0);
+        assert_eq!(p.with_x(5.0), Pt::new(5.0, 2.0));
+        assert_eq!(p.with_y(-3.0), Pt::new(1.0, -3.0));
+        assert_eq!(p.with_x(0.0).with_y(0.0), Pt::default());
+    }
+
+    #[test]
+    fn rotate() {
+        use std::f64::consts::{FRAC_PI_2, TAU};
+        let p = Pt::new(3.0f64, -1.5);
+        let r = p.rotate(TAU);
+        assert_approx_eq!(r.x, p.x);
+        assert_approx_eq!(r.y, p.y);
+        let r = p.rotate(FRAC_PI_2);
+        assert_approx_eq!(r.x, 1.5);
+        assert_approx_eq!(r.y, 3.0);
+        let r = Pt::new(1.0f32, 0.0).rotate(std::f32::consts::PI);
+        assert_approx_eq!(r.x, -1.0);
+        assert_approx_eq!(r.y, 0.0);
+    }
+
+    #[test]
+    fn rotate_around() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        let p = Pt::new(3.0f64, 1.0);
+        let r = p.rotate_around((2.0, 1.0), FRAC_PI_2);
+        assert_approx_eq!(r.x, 2.0);
+        assert_approx_eq!(r.y, 2.0);
+        let r = p.rotate_around((1.0, 1.0), PI);
+        assert_approx_eq!(r.x, -1.0);
+        assert_approx_eq!(r.y, 1.0);
+        assert_eq!(p.rotate_around(p, 1.3), p);
+    }
+
+    #[test]
+    fn rotate_cs() {
+        use crate::transform::Transform;
+        let th = 0.7f64;
+        let (sin, cos) = th.sin_cos();
+        let t = Transform::with_rotate(th);
+        for p in [Pt::new(1.0, 0.0), Pt::new(-3.0, 2.5), Pt::new(0.0, -4.0)] {
+            let r = p.rotate_cs(sin, cos);
+            let e = t * p;
+            assert_approx_eq!(r.x, e.x);
+            assert_approx_eq!(r.y, e.y);
+            assert_eq!(r, p.rotate(th));
+        }
+    }
+
+    #[test]
+    fn min_max() {
+        let a = Pt::new(2.0f32, 5.0);
+        let b = Pt::new(3.0, 4.0);
+        assert_eq!(a.with_min(b), Pt::new(2.0, 4.0));
+        assert_eq!(a.with_max(b), Pt::new(3.0, 5.0));
+        assert_eq!(a.component_min(b), a.with_min(b));
+        assert_eq!(a.component_max(b), a.with_max(b));
+        assert_eq!(a.component_min((1.0, 6.0)), Pt::new(1.0, 5.0));
+        assert_eq!(a.component_max((1.0, 6.0)), Pt::new(2.0, 6.0));
+    }
+
+    #[test]
+    fn clamp_components() {
+        let (mn, mx) = (Pt::new(0.0, -1.0), Pt::new(4.0, 1.0));
+        assert_eq!(
+            Pt::new(2.0, 0.5).clamp_components(mn, mx),
+            Pt::new(2.0, 0.5)
+        );
+        assert_eq!(
+            Pt::new(-3.0, 0.5).clamp_components(mn, mx),
+            Pt::new(0.0, 0.5)
+        );
+        assert_eq!(
+            Pt::new(7.0, 0.5).clamp_components(mn, mx),
+            Pt::new(4.0, 0.5)
+        );
+        assert_eq!(
+            Pt::new(2.0, -5.0).clamp_components(mn, mx),
+            Pt::new(2.0, -1.0)
+        );
+        assert_eq!(
+            Pt::new(2.0, 5.0).clamp_components(mn, mx),
+            Pt::new(2.0, 1.0)
+        );
+        assert_eq!(Pt::new(9.0, 9.0).clamp_components(mn, mx), mx);
+    }
+
+    #[test]
+    fn components() {
+        let a = Pt::new(5.0f32, 2.0);
+        assert_eq!(a.min_component(), 2.0);
+        assert_eq!(a.max_component(), 5.0);
+        assert_eq!(a.longest_axis(), 0);
+        let b = Pt::new(1.0f32, 3.0);
+        assert_eq!(b.min_component(), 1.0);
+        assert_eq!(b.max_component(), 3.0);
+        assert_eq!(b.longest_axis(), 1);
+        assert_eq!(Pt::new(1.0f32, -3.0).longest_axis(), 1);
+        assert_eq!(Pt::new(2.0f32, 2.0).longest_axis(), 0);
+    }
+
+    #[test]
+    fn abs_diff() {
+        let a = Pt::new(2.0f32, 5.0);
+        let b = Pt::new(3.5, -4.0);
+        assert_eq!(a.abs_diff(b), Pt::new(1.5, 9.0));
+        assert_eq!(b.abs_diff(a), Pt::new(1.5, 9.0));
+        assert_eq!(a.abs_diff(a), Pt::new(0.0, 0.0));
+    }
+
+    #[test]
+    fn from_int() {
+        assert_eq!(Pt::<f32>::from((3i32, -4i32)), Pt::new(3.0, -4.0));
+        assert_eq!(Pt::<f64>::from((3i32, -4i32)), Pt::new(3.0, -4.0));
+        assert_eq!(Pt::<f32>::from((7u32, 9u32)), Pt::new(7.0, 9.0));
+        assert_eq!(Pt::<f64>::from((7u32, 9u32)), Pt::new(7.0, 9.0));
+        let pt: Pt<f64> = (i32::MIN, u32::MAX as i32).into();
+        assert_eq!(pt, Pt::new(-2147483648.0, -1.0));
+        assert_eq!(Pt::new(1.0f32, 1.0).distance((4, 5)), 5.0);
+    }
+
+    #[test]
+    fn bytes() {
+        let a = Pt::new(1.5f32, -2.25);
+        assert_eq!(a.to_le_bytes(), [0, 0, 192, 63, 0, 0, 16, 192]);
+        assert_eq!(a.to_be_bytes(), [63, 192, 0, 0, 192, 16, 0, 0]);
+        assert_eq!(Pt::<f32>::from_le_bytes(a.to_le_bytes()), a);
+        assert_eq!(Pt::<f32>::from_be_bytes(a.to_be_bytes()), a);
+        let b = Pt::new(1.5f64, -2.25);
+        assert_eq!(b.to_le_bytes()[..8], 1.5f64.to_le_bytes());
+        assert_eq!(b.to_be_bytes()[8..], (-2.25f64).to_be_bytes());
+        assert_eq!(Pt::<f64>::from_le_bytes(b.to_le_bytes()), b);
+        assert_eq!(Pt::<f64>::from_be_bytes(b.to_be_bytes()), b);
+    }
+
+    #[test]
+    fn hash_pt() {
+        use std::collections::HashSet;
+        let mut pts = HashSet::new();
+        assert!(pts.insert(HashPt(Pt::new(1.0f32, 2.0))));
+        assert!(!pts.insert(HashPt(Pt::new(1.0, 2.0))));
+        assert!(pts.insert(HashPt(Pt::new(2.0, 1.0))));
+        assert!(pts.insert(HashPt(Pt::new(0.0, 0.0))));
+        assert!(!pts.insert(HashPt(Pt::new(-0.0, 0.0))));
+        assert!(pts.insert(HashPt(Pt::new(f32::NAN, 1.0))));
+        assert!(!pts.insert(HashPt(Pt::new(-f32::NAN, 1.0))));
+        assert_eq!(pts.len(), 4);
+    }
+
+    #[test]
+    fn centroid() {
+        let pts = [Pt::new(0.0f32, 0.0), Pt::new(6.0, 0.0), Pt::new(0.0, 3.0)];
+        assert_eq!(super::centroid(&pts), Some(Pt::new(2.0, 1.0)));
+        assert_eq!(super::centroid(&pts[1..2]), Some(Pt::new(6.0, 0.0)));
+        assert_eq!(super::centroid::<f32>(&[]), None);
+    }
+
+    #[test]
+    fn bilerp() {
+        let p00 = Pt::new(0.0f32, 0.0);
+        let p10 = Pt::new(1.0, 0.0);
+        let p01 = Pt::new(0.0, 1.0);
+        let p11 = Pt::new(1.0, 1.0);
+        let f = |u, v| super::bilerp(p00, p10, p01, p11, u, v);
+        assert_eq!(f(0.0, 0.0), p00);
+        assert_eq!(f(1.0, 0.0), p10);
+        assert_eq!(f(0.0, 1.0), p01);
+        assert_eq!(f(1.0, 1.0), p11);
+        assert_eq!(f(0.5, 0.5), Pt::new(0.5, 0.5));
+        let q = super::bilerp(p00, p10 * 2.0, p01, p11 * 4.0, 0.5, 0.5);
+        assert_eq!(q, Pt::new(1.5, 1.25));
+    }
+
+    #[test]
+    fn sort_angle() {
+        let center = Pt::new(1.0, 1.0);
+        let mut pts = [
+            Pt::new(1.0, 0.0),
+            Pt::new(3.0, 3.0),
+            Pt::new(0.0, 1.0),
+            Pt::new(2.0, 1.0),
+            Pt::new(2.0, 2.0),
+            Pt::new(0.0, 0.0),
+            Pt::new(1.0, 3.0),
+        ];
+        sort_by_angle(&mut pts, center);
+        assert_eq!(
+            pts,
+            [
+                Pt::new(2.0, 1.0),
+                Pt::new(2.0, 2.0),
+                Pt::new(3.0, 3.0),
+                Pt::new(1.0, 3.0),
+                Pt::new(0.0, 1.0),
+                Pt::new(0.0, 0.0),
+                Pt::new(1.0, 0.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn dedup() {
+        let pts = [
+            Pt::new(0.0f32, 0.0),
+            Pt::new(1.0, 1.0),
+            Pt::new(1.001, 1.0),
+            Pt::new(1.0, 0.999),
+            Pt::new(2.0, 0.0),
+            Pt::new(0.0, 0.0),
+        ];
+        let out = dedup_points(&pts, 0.01);
+        assert_eq!(out, [pts[0], pts[1], pts[4], pts[5]]);
+        assert_eq!(dedup_points::<f32>(&[], 0.01), []);
+    }
+
+    #[test]
+    fn from_polar() {
+        for th in [0.0f64, 0.3, 1.9, -2.5, 4.0] {
+            assert_eq!(Pt::from_polar(1.0, th), Pt::from_angle(th));
+        }
+        let p = Pt::from_polar(2.0f64, std::f64::consts::FRAC_PI_2);
+        assert_approx_eq!(p.x, 0.0);
+        assert_approx_eq!(p.y, 2.0);
+        assert_eq!(Pt::from_polar(3.0, 0.0), Pt::new(3.0, 0.0));
+    }
+
+    #[test]
+    fn to_polar() {
+        use std::f64::consts::{FRAC_PI_2, PI};
+        assert_eq!(Pt::new(3.0f64, 0.0).to_polar(), (3.0, 0.0));
+        let (r, th) = Pt::new(0.0f64, -2.0).to_polar();
+        assert_eq!(r, 2.0);
+        assert_approx_eq!(th, -FRAC_PI_2);
+        let (r, th) = Pt::from_polar(1.5f64, 2.0).to_polar();
+        assert_approx_eq!(r, 1.5);
+        assert_approx_eq!(th, 2.0);
+        assert_eq!(Pt::new(-1.0f64, 0.0).to_polar(), (1.0, PI));
+        assert_eq!(Pt::<f32>::default().to_polar(), (0.0, 0.0));
+    }
+
+    #[test]
+    fn angle_2pi() {
+        use std::f64::consts::{FRAC_PI_4, PI};
+        assert_eq!(Pt::new(1.0f64, 0.0).angle_2pi(), 0.0);
+        assert_approx_eq!(Pt::new(1.0f64, 1.0).angle_2pi(), FRAC_PI_4);
+        assert_approx_eq!(Pt::new(-1.0f64, 1.0).angle_2pi(), 3.0 * FRAC_PI_4);
+        assert_approx_eq!(Pt::new(-1.0f64, 0.0).angle_2pi(), PI);
+        assert_approx_eq!(Pt::new(-1.0f64, -1.0).angle_2pi(), 5.0 * FRAC_PI_4);
+        assert_approx_eq!(Pt::new(1.0f64, -1.0).angle_2pi(), 7.0 * FRAC_PI_4);
+        assert_eq!(Pt::new(1.0f64, -1e-300).angle_2pi(), 0.0);
+    }
+
+    #[test]
+    fn angle_to() {
+        use std::f32::consts::PI;
+        assert_eq!(Pt::new(0.0, 0.0).angle_to((0.0, 1.0)), PI / 2.0);
+        assert_eq!(Pt::new(0.0, 0.0).angle_to((-1.0, 0.0)), PI);
+        assert_eq!(Pt::new(2.0, 3.0).angle_to((3.0, 4.0)), PI / 4.0);
+    }
+
+    #[test]
+    fn rotate_toward() {
+        use std::f32::consts::PI;
+        let v = Pt::new(2.0f32, 0.0).rotate_toward((0.0, 5.0), 0.1);
+        assert_approx_eq!(v.mag(), 2.0);
+        assert_approx_eq!(v.angle(), 0.1);
+        let v = Pt::new(2.0f32, 0.0).rotate_toward((0.0, -5.0), 0.1);
+        assert_approx_eq!(v.angle(), -0.1);
+        let v = Pt::new(0.0f32, 3.0).rotate_toward((1.0, 1.0), PI / 2.0);
+        assert_approx_eq!(v.x, 2.1213202);
+        assert_approx_eq!(v.y, 2.1213202);
+    }
+
+    #[test]
+    fn polar_offset() {
+        use std::f32::consts::PI;
+        let o = Pt::new(0.0f32, 0.0);
+        assert_eq!(o.polar_offset(1.0, 0.0), Pt::new(1.0, 0.0));
+        let v = o.polar_offset(1.0, PI / 2.0);
+        assert_approx_eq!(v.x, 0.0);
+        assert_approx_eq!(v.y, 1.0);
+        let v = o.polar_offset(1.0, PI);
+        assert_approx_eq!(v.x, -1.0);
+        assert_approx_eq!(v.y, 0.0);
+        let v = Pt::new(2.0f32, 3.0).polar_offset(2.0, PI / 4.0);
+        assert_approx_eq!(v.x, 3.4142137);
+        assert_approx_eq!(v.y, 4.4142137);
+    }
+
+    #[test]
+    fn snap_direction() {
+        let v = Pt::new(3.0f32, 1.0).snap_direction(4);
+        assert_approx_eq!(v.x, 1.0);
+        assert_approx_eq!(v.y, 0.0);
+        let v = Pt::new(-1.0f32, 3.0).snap_direction(4);
+        assert_approx_eq!(v.x, 0.0);
+        assert_approx_eq!(v.y, 1.0);
+        let v = Pt::new(-2.0f32, -1.5).snap_direction(8);
+        assert_approx_eq!(v.x, -0.70710677);
+        assert_approx_eq!(v.y, -0.70710677);
+        let v = Pt::new(-2.0f32, -0.5).snap_direction(8);
+        assert_approx_eq!(v.x, -1.0);
+        assert_approx_eq!(v.y, 0.0);
+        assert_eq!(Pt::new(0.0f32, 0.0).snap_direction(8), Pt::default());
+    }
+
     #[test]
     fn angles() {
         let a = Pt::new(2.0f32, 1.0);
@@ -346,7 +1440,9 @@ mod test {
         assert_eq!(Pt::new(0.0, 0.0).angle(), 0.0);
         assert_eq!(Pt::new(-1.0, 0.0).angle(), std::f32::consts::PI);
         assert_eq!(a.angle_rel(b), -0.4636476);
-        assert_eq!(c.angle_rel((1.0, 1.0)), 1.5707963f32);
+        // one ulp below PI / 2
+        let th = std::f32::consts::FRAC_PI_2 - f32::EPSILON;
+        assert_eq!(c.angle_rel((1.0, 1.0)), th);
         assert_eq!(Pt::new(-1.0f32, -1.0).angle_rel(c), 1.5707965);
         let v = Pt::from(0.0f32);
         assert_approx_eq!(v.x, 1.0);
@@ -0,0 +1,310 @@
+// polygon.rs   2D Polygons
+//
+// Copyright (c) 2024  Douglas P Lau
+//
+use crate::bbox::BBox;
+use crate::float::Float;
+use crate::line::Line;
+use crate::point::Pt;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Rule for determining which points are inside a polygon
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FillRule {
+    /// Points with a non-zero winding number are inside
+    NonZero,
+    /// Points with an odd winding number are inside
+    EvenOdd,
+}
+
+/// A polygon, defined by its vertices
+///
+/// The last vertex is implicitly connected to the first.
+///
+/// ```rust
+/// use pointy::{Polygon, Pt};
+///
+/// let poly = Polygon(vec![
+///     Pt::new(0.0, 0.0),
+///     Pt::new(4.0, 0.0),
+///     Pt::new(0.0, 3.0),
+/// ]);
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Polygon<F>(pub Vec<Pt<F>>)
+where
+    F: Float;
+
+impl<F> Polygon<F>
+where
+    F: Float,
+{
+    /// Get an iterator of edges, as pairs of vertices
+    fn edges(&self) -> impl Iterator<Item = (Pt<F>, Pt<F>)> + '_ {
+        let n = self.0.len();
+        (0..n).map(move |i| (self.0[i], self.0[(i + 1) % n]))
+    }
+
+    /// Get the signed area.
+    ///
+    /// The area is positive if the vertices are in counter-clockwise order.
+    pub fn area(&self) -> F {
+        let two = F::one() + F::one();
+        self.edges().fold(F::zero(), |acc, (a, b)| acc + a * b) / two
+    }
+
+    /// Get the centroid (center of mass).
+    ///
+    /// Returns `None` if the area is zero.
+    pub fn centroid(&self) -> Option<Pt<F>> {
+        let area = self.area();
+        if area == F::zero() {
+            return None;
+        }
+        let six = F::from(6).unwrap();
+        let sum = self
+            .edges()
+            .fold(Pt::default(), |acc, (a, b)| acc + (a + b) * (a * b));
+        Some(sum / (six * area))
+    }
+
+    /// Get the winding number around a point
+    fn winding_number(&self, pt: Pt<F>) -> i32 {
+        let mut wn = 0;
+        for (a, b) in self.edges() {
+            let side = (b - a) * (pt - a);
+            if a.y <= pt.y {
+                if b.y > pt.y && side > F::zero() {
+                    wn += 1;
+                }
+            } else if b.y <= pt.y && side < F::zero() {
+                wn -= 1;
+            }
+        }
+        wn
+    }
+
+    /// Check if the polygon contains a point
+    pub fn contains<P>(&self, pt: P, rule: FillRule) -> bool
+    where
+        P: Into<Pt<F>>,
+    {
+        let wn = self.winding_number(pt.into());
+        match rule {
+            FillRule::NonZero => wn != 0,
+            FillRule::EvenOdd => wn % 2 != 0,
+        }
+    }
+
+    /// Get the bounding box
+    pub fn bounds(&self) -> BBox<F> {
+        BBox::new(&self.0)
+    }
+
+    /// Check if the polygon is convex.
+    ///
+    /// Collinear vertices are allowed.  Polygons with fewer than three
+    /// vertices are not convex, nor are self-intersecting polygons.
+    pub fn is_convex(&self) -> bool {
+        if self.0.len() < 3 {
+            return false;
+        }
+        let zero = F::zero();
+        let mut pos = false;
+        let mut neg = false;
+        // count sign changes of edge directions on each axis; a convex
+        // polygon winds around only once, so it changes twice
+        let mut x_flips = 0;
+        let mut y_flips = 0;
+        let mut x_sign = zero;
+        let mut y_sign = zero;
+        let mut edges = self.edges().map(|(a, b)| b - a).collect::<Vec<_>>();
+        edges.push(edges[0]);
+        for v in edges.windows(2) {
+            let turn = v[0] * v[1];
+            pos |= turn > zero;
+            neg |= turn < zero;
+            for (d, sign, flips) in [
+                (v[1].x, &mut x_sign, &mut x_flips),
+                (v[1].y, &mut y_sign, &mut y_flips),
+            ] {
+                if d != zero {
+                    if d.signum() != *sign && *sign != zero {
+                        *flips += 1;
+                    }
+                    *sign = d.signum();
+                }
+            }
+        }
+        pos != neg && x_flips <= 2 && y_flips <= 2
+    }
+}
+
+/// Clip a polygon to a convex region (Sutherland–Hodgman).
+///
+/// The clip region may be in either winding order, and may repeat its
+/// first vertex at the end.  Returns the vertices of the clipped polygon,
+/// which is empty if nothing is inside or the clip region has fewer than
+/// three distinct vertices.
+///
+/// * `subject` Vertices of polygon to clip.
+/// * `clip_convex` Vertices of convex clip region.
+pub fn clip_polygon<F: Float>(
+    subject: &[Pt<F>],
+    clip_convex: &[Pt<F>],
+) -> Vec<Pt<F>> {
+    let mut distinct: Vec<Pt<F>> = Vec::with_capacity(clip_convex.len());
+    for pt in clip_convex {
+        if !distinct.contains(pt) {
+            distinct.push(*pt);
+        }
+    }
+    if distinct.len() < 3 {
+        return Vec::new();
+    }
+    let clip = Polygon(clip_convex.to_vec());
+    let sign = if clip.area() < F::zero() {
+        -F::one()
+    } else {
+        F::one()
+    };
+    let mut output = subject.to_vec();
+    // zero-length edges (from repeated vertices) have no direction
+    for (a, b) in clip.edges().filter(|(a, b)| a != b) {
+        if output.is_empty() {
+            break;
+        }
+        let line = Line::new(a, b);
+        let input = std::mem::take(&mut output);
+        let mut prev = input[input.len() - 1];
+        let mut dp = line.signed_distance(prev) * sign;
+        for &cur in &input {
+            let dc = line.signed_distance(cur) * sign;
+            if (dc >= F::zero()) != (dp >= F::zero()) {
+                output.push(prev + (cur - prev) * (dp / (dp - dc)));
+            }
+            if dc >= F::zero() {
+                output.push(cur);
+            }
+            prev = cur;
+            dp = dc;
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use assert_approx_eq::*;
+
+    #[test]
+    fn convex() {
+        let p = Polygon(vec![
+            Pt::new(0.0, 0.0),
+            Pt::new(4.0, 0.0),
+            Pt::new(4.0, 2.0),
+            Pt::new(0.0, 2.0),
+        ]);
+        assert_eq!(p.area(), 8.0);
+        assert_eq!(p.centroid(), Some(Pt::new(2.0, 1.0)));
+        assert!(p.contains((1.0, 1.0), FillRule::NonZero));
+        assert!(p.contains((1.0, 1.0), FillRule::EvenOdd));
+        assert!(!p.contains((5.0, 1.0), FillRule::NonZero));
+        assert_eq!(p.bounds(), BBox::new([(0.0, 0.0), (4.0, 2.0)]));
+        assert!(p.is_convex());
+        let mut r = p.clone();
+        r.0.reverse();
+        assert_eq!(r.area(), -8.0);
+        assert_eq!(r.centroid(), Some(Pt::new(2.0, 1.0)));
+        assert!(r.contains((1.0, 1.0), FillRule::NonZero));
+        assert!(r.is_convex());
+    }
+
+    #[test]
+    fn concave() {
+        let p = Polygon(vec![
+            Pt::new(0.0f64, 0.0),
+            Pt::new(2.0, 0.0),
+            Pt::new(2.0, 1.0),
+            Pt::new(1.0, 1.0),
+            Pt::new(1.0, 2.0),
+            Pt::new(0.0, 2.0),
+        ]);
+        assert_eq!(p.area(), 3.0);
+        let c = p.centroid().unwrap();
+        assert_approx_eq!(c.x, 5.0 / 6.0);
+        assert_approx_eq!(c.y, 5.0 / 6.0);
+        assert!(p.contains((0.5, 1.5), FillRule::NonZero));
+        assert!(p.contains((1.5, 0.5), FillRule::EvenOdd));
+        assert!(!p.contains((1.5, 1.5), FillRule::NonZero));
+        assert!(!p.contains((1.5, 1.5), FillRule::EvenOdd));
+        assert_eq!(p.bounds(), BBox::new([(0.0, 0.0), (2.0, 2.0)]));
+        assert!(!p.is_convex());
+    }
+
+    #[test]
+    fn clip() {
+        let tri = [Pt::new(0.0f64, 0.0), Pt::new(4.0, 0.0), Pt::new(0.0, 4.0)];
+        let mut square = [
+            Pt::new(0.0, 0.0),
+            Pt::new(3.0, 0.0),
+            Pt::new(3.0, 3.0),
+            Pt::new(0.0, 3.0),
+        ];
+        let c = Polygon(clip_polygon(&tri, &square));
+        assert_eq!(c.0.len(), 5);
+        assert_approx_eq!(c.area(), 7.0);
+        for pt in [(0.0, 0.0), (3.0, 0.0), (3.0, 1.0), (1.0, 3.0), (0.0, 3.0)] {
+            assert!(c.0.iter().any(|p| p.distance(pt) < 1e-9));
+        }
+        square.reverse();
+        let c = Polygon(clip_polygon(&tri, &square));
+        assert_approx_eq!(c.area(), 7.0);
+        let far = [Pt::new(5.0, 5.0), Pt::new(6.0, 5.0), Pt::new(5.0, 6.0)];
+        assert!(clip_polygon(&far, &square).is_empty());
+    }
+
+    #[test]
+    fn clip_closed_ring() {
+        let tri = [Pt::new(0.0f64, 0.0), Pt::new(4.0, 0.0), Pt::new(0.0, 4.0)];
+        let ring = [
+            Pt::new(0.0, 0.0),
+            Pt::new(3.0, 0.0),
+            Pt::new(3.0, 3.0),
+            Pt::new(0.0, 3.0),
+            Pt::new(0.0, 0.0),
+        ];
+        let c = Polygon(clip_polygon(&tri, &ring));
+        assert_eq!(c.0.len(), 5);
+        assert_approx_eq!(c.area(), 7.0);
+        assert_eq!(clip_polygon(&tri, &ring[..4]), clip_polygon(&tri, &ring));
+    }
+
+    #[test]
+    fn clip_degenerate() {
+        let tri = [Pt::new(0.0f64, 0.0), Pt::new(4.0, 0.0), Pt::new(0.0, 4.0)];
+        assert!(clip_polygon(&tri, &[]).is_empty());
+        let seg = [Pt::new(0.0, 0.0), Pt::new(3.0, 0.0), Pt::new(0.0, 0.0)];
+        assert!(clip_polygon(&tri, &seg).is_empty());
+    }
+
+    #[test]
+    fn fill_rule() {
+        // pentagram
+        let p = Polygon(vec![
+            Pt::new(0.0, 3.0),
+            Pt::new(-1.8, -2.4),
+            Pt::new(2.9, 0.9),
+            Pt::new(-2.9, 0.9),
+            Pt::new(1.8, -2.4),
+        ]);
+        assert!(p.contains((0.0, 0.0), FillRule::NonZero));
+        assert!(!p.contains((0.0, 0.0), FillRule::EvenOdd));
+        assert!(p.contains((0.0, 2.0), FillRule::EvenOdd));
+        assert!(!p.is_convex());
+        assert_eq!(Polygon::<f32>::default().centroid(), None);
+    }
+}
@@ -2,7 +2,9 @@
 //
 // Copyright (c) 2020-2022  Douglas P Lau
 //
+use crate::bbox::BBox;
 use crate::float::Float;
+use crate::line::Line;
 use crate::point::Pt;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -161,6 +163,18 @@ where
         }
     }
 
+    /// Create a new scale transform about a center point.
+    ///
+    /// * `sx` Scale factor for X dimension.
+    /// * `sy` Scale factor for Y dimension.
+    /// * `cx` X coordinate of center.
+    /// * `cy` Y coordinate of center.
+    pub fn with_scale_about(sx: F, sy: F, cx: F, cy: F) -> Self {
+        Self::with_translate(-cx, -cy)
+            .scale(sx, sy)
+            .translate(cx, cy)
+    }
+
     /// Create a new rotation transform.
     ///
     /// * `th` Angle to rotate coordinates (radians).
@@ -172,6 +186,22 @@ where
         }
     }
 
+    /// Create a new rotation transform about a center point.
+    ///
+    /// * `th` Angle to rotate coordinates (radians).
+    /// * `cx` X coordinate of center.
+    /// * `cy` Y coordinate of center.
+    pub fn with_rotate_about(th: F, cx: F, cy: F) -> Self {
+        Self::with_translate(-cx, -cy).rotate(th).translate(cx, cy)
+    }
+
+    /// Create a new rotation transform in degrees.
+    ///
+    /// * `deg` Angle to rotate coordinates (degrees).
+    pub fn with_rotate_deg(deg: F) -> Self {
+        Self::with_rotate(deg.to_radians())
+    }
+
     /// Create a new skew transform.
     ///
     /// * `ax` Angle to skew X-axis (radians).
@@ -184,6 +214,137 @@ where
         }
     }
 
+    /// Create a new skew transform in degrees.
+    ///
+    /// * `ax` Angle to skew X-axis (degrees).
+    /// * `ay` Angle to skew Y-axis (degrees).
+    pub fn with_skew_deg(ax: F, ay: F) -> Self {
+        Self::with_skew(ax.to_radians(), ay.to_radians())
+    }
+
+    /// Compose a sequence of transforms.
+    ///
+    /// Transforms are multiplied left-to-right, so the first is applied
+    /// first.  An empty slice results in the identity transform.
+    pub fn compose_all(transforms: &[Self]) -> Self {
+        transforms.iter().fold(Self::default(), |acc, t| acc * *t)
+    }
+
+    /// Create a transform mapping the unit square onto a parallelogram.
+    ///
+    /// The point (0, 0) maps to `origin`, (1, 0) to `origin + x_axis` and
+    /// (0, 1) to `origin + y_axis`.
+    ///
+    /// * `origin` Parallelogram corner.
+    /// * `x_axis` Vector along first side.
+    /// * `y_axis` Vector along second side.
+    pub fn from_unit_square(
+        origin: Pt<F>,
+        x_axis: Pt<F>,
+        y_axis: Pt<F>,
+    ) -> Self {
+        Self {
+            e: [x_axis.x, y_axis.x, origin.x, x_axis.y, y_axis.y, origin.y],
+        }
+    }
+
+    /// Create a new transform reflecting across a line.
+    ///
+    /// * `line` Line to reflect across.
+    pub fn with_reflect_line(line: Line<F>) -> Self {
+        let th = (line.p1 - line.p0).angle();
+        Self::with_translate(-line.p0.x, -line.p0.y)
+            .rotate(-th)
+            .scale(F::one(), -F::one())
+            .rotate(th)
+            .translate(line.p0.x, line.p0.y)
+    }
+
+    /// Get the translation component
+    pub fn translation(self) -> Pt<F> {
+        Pt::new(self.e[2], self.e[5])
+    }
+
+    /// Get the determinant of the linear part.
+    ///
+    /// This is negative if the transform includes a reflection.
+    pub fn determinant(self) -> F {
+        let [a, b, _, c, d, _] = self.e;
+        a * d - b * c
+    }
+
+    /// Get the factor by which areas are scaled
+    pub fn area_scale(self) -> F {
+        self.determinant().abs()
+    }
+
+    /// Check if the transform is conformal (angle-preserving).
+    ///
+    /// This is true when the linear part is a rotation with uniform
+    /// scaling (possibly reflected); that is, its columns are orthogonal
+    /// and of equal length, within `epsilon`.
+    pub fn is_conformal(self, epsilon: F) -> bool {
+        let [a, b, _, c, d, _] = self.e;
+        let dot = a * b + c * d;
+        let len0 = a * a + c * c;
+        let len1 = b * b + d * d;
+        dot.abs() <= epsilon && (len0 - len1).abs() <= epsilon
+    }
+
+    /// Transform a point.
+    ///
+    /// This includes translation, and is the same as multiplying by a
+    /// [Pt].
+    pub fn map_point<P>(self, pt: P) -> Pt<F>
+    where
+        P: Into<Pt<F>>,
+    {
+        self * pt.into()
+    }
+
+    /// Transform a vector (direction).
+    ///
+    /// Unlike [map_point](Self::map_point), translation is not applied;
+    /// only the linear (scale / rotate / skew) part is used.
+    pub fn map_vector<P>(self, v: P) -> Pt<F>
+    where
+        P: Into<Pt<F>>,
+    {
+        let v = v.into();
+        let x = self.e[0] * v.x + self.e[1] * v.y;
+        let y = self.e[3] * v.x + self.e[4] * v.y;
+        Pt::new(x, y)
+    }
+
+    /// Transform a bounding box.
+    ///
+    /// Returns the axis-aligned box containing the transformed corners,
+    /// calculated directly from the center and half-extents.
+    pub fn transform_bbox(self, bbox: BBox<F>) -> BBox<F> {
+        if bbox.x_min() > bbox.x_max() || bbox.y_min() > bbox.y_max() {
+            return BBox::default();
+        }
+        let two = F::one() + F::one();
+        let center = self * Pt::new(bbox.x_mid(), bbox.y_mid());
+        let hx = bbox.x_span() / two;
+        let hy = bbox.y_span() / two;
+        let half = Pt::new(
+            self.e[0].abs() * hx + self.e[1].abs() * hy,
+            self.e[3].abs() * hx + self.e[4].abs() * hy,
+        );
+        BBox::new([center - half, center + half])
+    }
+
+    /// Apply transform to a sequence of points.
+    ///
+    /// Points are transformed lazily as the iterator is consumed.
+    pub fn apply<I>(self, pts: I) -> impl Iterator<Item = Pt<F>>
+    where
+        I: IntoIterator<Item = Pt<F>>,
+    {
+        pts.into_iter().map(move |pt| self * pt)
+    }
+
     /// Apply translation to a transform.
     ///
     /// * `tx` Amount to translate X.
@@ -202,6 +363,17 @@ where
         self
     }
 
+    /// Apply scaling about a center point to a transform.
+    ///
+    /// * `sx` Scale factor for X dimension.
+    /// * `sy` Scale factor for Y dimension.
+    /// * `cx` X coordinate of center.
+    /// * `cy` Y coordinate of center.
+    pub fn scale_about(mut self, sx: F, sy: F, cx: F, cy: F) -> Self {
+        self *= Self::with_scale_about(sx, sy, cx, cy);
+        self
+    }
+
     /// Apply rotation to a transform.
     ///
     /// * `th` Angle to rotate coordinates (radians).
@@ -210,6 +382,24 @@ where
         self
     }
 
+    /// Apply rotation in degrees to a transform.
+    ///
+    /// * `deg` Angle to rotate coordinates (degrees).
+    pub fn rotate_deg(mut self, deg: F) -> Self {
+        self *= Self::with_rotate_deg(deg);
+        self
+    }
+
+    /// Apply rotation about a center point to a transform.
+    ///
+    /// * `th` Angle to rotate coordinates (radians).
+    /// * `cx` X coordinate of center.
+    /// * `cy` Y coordinate of center.
+    pub fn rotate_about(mut self, th: F, cx: F, cy: F) -> Self {
+        self *= Self::with_rotate_about(th, cx, cy);
+        self
+    }
+
     /// Apply skew to a transform.
     ///
     /// * `ax` Angle to skew X-axis (radians).
@@ -218,11 +408,75 @@ where
         self *= Self::with_skew(ax, ay);
         self
     }
+
+    /// Apply skew in degrees to a transform.
+    ///
+    /// * `ax` Angle to skew X-axis (degrees).
+    /// * `ay` Angle to skew Y-axis (degrees).
+    pub fn skew_deg(mut self, ax: F, ay: F) -> Self {
+        self *= Self::with_skew_deg(ax, ay);
+        self
+    }
+
+    /// Apply translation before a transform.
+    ///
+    /// Unlike [translate](Self::translate), the translation is applied
+    /// before any existing operations.
+    ///
+    /// ```
+    /// use pointy::{Pt, Transform};
+    ///
+    /// let t = Transform::with_rotate(std::f32::consts::PI / 2.0);
+    /// let pt = t.translate(1.0, 0.0) * Pt::new(0.0, 0.0);
+    /// assert_eq!(pt, Pt::new(1.0, 0.0));
+    /// let pt = t.pre_translate(1.0, 0.0) * Pt::new(0.0, 0.0);
+    /// assert_eq!(pt.y, 1.0);
+    /// ```
+    ///
+    /// * `tx` Amount to translate X.
+    /// * `ty` Amount to translate Y.
+    pub fn pre_translate(self, tx: F, ty: F) -> Self {
+        Self::with_translate(tx, ty) * self
+    }
+
+    /// Apply scaling before a transform.
+    ///
+    /// Unlike [scale](Self::scale), the scaling is applied before any
+    /// existing operations.
+    ///
+    /// * `sx` Scale factor for X dimension.
+    /// * `sy` Scale factor for Y dimension.
+    pub fn pre_scale(self, sx: F, sy: F) -> Self {
+        Self::with_scale(sx, sy) * self
+    }
+
+    /// Apply rotation before a transform.
+    ///
+    /// Unlike [rotate](Self::rotate), the rotation is applied before any
+    /// existing operations.
+    ///
+    /// * `th` Angle to rotate coordinates (radians).
+    pub fn pre_rotate(self, th: F) -> Self {
+        Self::with_rotate(th) * self
+    }
+}
+
+/// Get the bounding box of a set of transformed points.
+///
+/// This is tighter than [transforming](Transform::transform_bbox) the
+/// bounding box of the points, unless the transform only translates or
+/// scales.
+pub fn transform_points_bounds<F: Float>(
+    pts: &[Pt<F>],
+    t: Transform<F>,
+) -> BBox<F> {
+    BBox::new(t.apply(pts.iter().copied()))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use assert_approx_eq::*;
 
     #[test]
     fn test_identity() {
@@ -269,6 +523,19 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_scale_about() {
+        assert_eq!(
+            Transform::with_scale_about(2.0, 3.0, 1.0, 1.0).e,
+            [2.0, 0.0, -1.0, 0.0, 3.0, -2.0]
+        );
+        let t = Transform::with_translate(5.0, -2.0)
+            .scale_about(2.0, 0.5, 6.0, 2.0);
+        assert_eq!(t * Pt::new(1.0, 4.0), Pt::new(6.0, 2.0));
+        assert_eq!(t * Pt::new(2.0, 4.0), Pt::new(8.0, 2.0));
+        assert_eq!(t * Pt::new(1.0, 6.0), Pt::new(6.0, 3.0));
+    }
+
     #[test]
     fn test_rotate() {
         const PI: f32 = std::f32::consts::PI;
@@ -284,6 +551,51 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_rotate_about() {
+        const PI: f64 = std::f64::consts::PI;
+        let t = Transform::with_rotate_about(PI / 2.0, 2.0, 1.0);
+        let p = t * Pt::new(2.0, 1.0);
+        assert_approx_eq!(p.x, 2.0);
+        assert_approx_eq!(p.y, 1.0);
+        let p = t * Pt::new(3.0, 1.0);
+        assert_approx_eq!(p.x, 2.0);
+        assert_approx_eq!(p.y, 2.0);
+        let t = Transform::default()
+            .translate(1.0, 3.0)
+            .rotate_about(PI, 4.0, 4.0);
+        let p = t * Pt::new(3.0, 1.0);
+        assert_approx_eq!(p.x, 4.0);
+        assert_approx_eq!(p.y, 4.0);
+        let p = t * Pt::new(4.0, 1.0);
+        assert_approx_eq!(p.x, 3.0);
+        assert_approx_eq!(p.y, 4.0);
+    }
+
+    #[test]
+    fn test_degrees() {
+        const PI: f64 = std::f64::consts::PI;
+        let a = Transform::with_translate(1.0, 2.0).rotate_deg(90.0);
+        let b = Transform::with_translate(1.0, 2.0).rotate(PI / 2.0);
+        for (a, b) in a.e.iter().zip(b.e.iter()) {
+            assert_approx_eq!(a, b);
+        }
+        let a = Transform::with_rotate_deg(-30.0);
+        let b = Transform::with_rotate(-PI / 6.0);
+        for (a, b) in a.e.iter().zip(b.e.iter()) {
+            assert_approx_eq!(a, b);
+        }
+        let a = Transform::default().skew_deg(45.0, 10.0);
+        let b = Transform::with_skew(PI / 4.0, PI / 18.0);
+        for (a, b) in a.e.iter().zip(b.e.iter()) {
+            assert_approx_eq!(a, b);
+        }
+        assert_eq!(
+            Transform::<f64>::with_skew_deg(0.0, 0.0).e,
+            [1.0, 0.0, 0.0, 0.0, 1.0, 0.0]
+        );
+    }
+
     #[test]
     fn test_skew() {
         const PI: f32 = std::f32::consts::PI;
@@ -313,6 +625,162 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_pre() {
+        const PI: f32 = std::f32::consts::PI;
+        let t = Transform::with_rotate(PI / 2.0);
+        let pt = t.translate(1.0, 0.0) * Pt::new(0.0, 0.0);
+        assert_eq!(pt, Pt::new(1.0, 0.0));
+        let pt = t.pre_translate(1.0, 0.0) * Pt::new(0.0, 0.0);
+        assert_approx_eq!(pt.x, 0.0);
+        assert_approx_eq!(pt.y, 1.0);
+        let t = Transform::with_translate(1.0, 2.0);
+        assert_eq!(t.scale(2.0, 3.0) * Pt::new(1.0, 1.0), Pt::new(4.0, 9.0));
+        assert_eq!(
+            t.pre_scale(2.0, 3.0) * Pt::new(1.0, 1.0),
+            Pt::new(3.0, 5.0)
+        );
+        assert_eq!(
+            t.pre_rotate(PI / 2.0),
+            Transform::with_rotate(PI / 2.0).translate(1.0, 2.0)
+        );
+    }
+
+    #[test]
+    fn test_unit_square() {
+        let o = Pt::new(2.0, 1.0);
+        let x = Pt::new(3.0, 1.0);
+        let y = Pt::new(1.0, 2.0);
+        let t = Transform::from_unit_square(o, x, y);
+        assert_eq!(t * (0.0, 0.0), o);
+        assert_eq!(t * (1.0, 0.0), o + x);
+        assert_eq!(t * (0.0, 1.0), o + y);
+        assert_eq!(t * (1.0, 1.0), o + x + y);
+        assert_eq!(t * (0.5, 0.5), Pt::new(4.0, 2.5));
+    }
+
+    #[test]
+    fn test_reflect_line() {
+        let t =
+            Transform::with_reflect_line(Line::new((0.0f64, 1.0), (1.0, 2.0)));
+        let pt = t * Pt::new(0.0, 0.0);
+        assert_approx_eq!(pt.x, -1.0);
+        assert_approx_eq!(pt.y, 1.0);
+        let pt = t * Pt::new(2.0, 3.0);
+        assert_approx_eq!(pt.x, 2.0);
+        assert_approx_eq!(pt.y, 3.0);
+        let pt = t * t * Pt::new(5.0, -7.0);
+        assert_approx_eq!(pt.x, 5.0);
+        assert_approx_eq!(pt.y, -7.0);
+    }
+
+    #[test]
+    fn test_translation() {
+        const PI: f64 = std::f64::consts::PI;
+        let t = Transform::with_translate(3.0, 1.0).rotate(PI / 2.0);
+        let p = t.translation();
+        assert_approx_eq!(p.x, -1.0);
+        assert_approx_eq!(p.y, 3.0);
+        assert_eq!(p, t * Pt::new(0.0, 0.0));
+        assert_eq!(Transform::<f32>::default().translation(), Pt::default());
+    }
+
+    #[test]
+    fn test_area_scale() {
+        let t = Transform::with_scale(2.0, 3.0);
+        assert_eq!(t.determinant(), 6.0);
+        assert_eq!(t.area_scale(), 6.0);
+        assert_approx_eq!(Transform::with_rotate(0.9f64).area_scale(), 1.0);
+        let t = Transform::with_scale(-1.0, 1.0);
+        assert_eq!(t.determinant(), -1.0);
+        assert_eq!(t.area_scale(), 1.0);
+        let t = Transform::with_translate(4.0, 5.0).scale(0.5, 0.5);
+        assert_eq!(t.area_scale(), 0.25);
+    }
+
+    #[test]
+    fn test_conformal() {
+        const PI: f32 = std::f32::consts::PI;
+        let t = Transform::with_rotate(0.7)
+            .scale(3.0, 3.0)
+            .translate(5.0, 1.0);
+        assert!(t.is_conformal(1e-5));
+        assert!(Transform::<f32>::default().is_conformal(0.0));
+        assert!(!Transform::with_scale(2.0, 3.0).is_conformal(1e-5));
+        assert!(!Transform::with_skew(PI / 8.0, 0.0).is_conformal(1e-5));
+        let t = Transform::with_rotate(1.2).scale(2.0, 2.01);
+        assert!(!t.is_conformal(1e-5));
+        assert!(t.is_conformal(0.1));
+    }
+
+    #[test]
+    fn test_map() {
+        let t = Transform::with_scale(2.0, 3.0).translate(5.0, -1.0);
+        assert_eq!(t.map_point((1.0, 1.0)), Pt::new(7.0, 2.0));
+        assert_eq!(t.map_point((1.0, 1.0)), t * Pt::new(1.0, 1.0));
+        assert_eq!(t.map_vector((1.0, 1.0)), Pt::new(2.0, 3.0));
+        let t = Transform::with_translate(4.0, 4.0);
+        assert_eq!(t.map_point((0.0, 1.0)), Pt::new(4.0, 5.0));
+        assert_eq!(t.map_vector((0.0, 1.0)), Pt::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_transform_bbox() {
+        let b = BBox::new([(1.0f64, 2.0), (4.0, 3.0)]);
+        for t in [
+            Transform::with_rotate(0.6).translate(3.0, -1.0),
+            Transform::with_skew(0.3, -0.2).scale(2.0, 0.5),
+            Transform::with_scale(-1.0, 2.0),
+        ] {
+            let corners = BBox::new(t.apply(b.corners_ccw()));
+            let tb = t.transform_bbox(b);
+            assert_approx_eq!(tb.x_min(), corners.x_min());
+            assert_approx_eq!(tb.x_max(), corners.x_max());
+            assert_approx_eq!(tb.y_min(), corners.y_min());
+            assert_approx_eq!(tb.y_max(), corners.y_max());
+        }
+        let t = Transform::with_translate(1.0, 1.0);
+        assert_eq!(t.transform_bbox(BBox::default()), BBox::default());
+    }
+
+    #[test]
+    fn test_points_bounds() {
+        use crate::bbox::Bounded;
+        const PI: f64 = std::f64::consts::PI;
+        let pts = [Pt::new(0.0, 0.0), Pt::new(4.0, 4.0), Pt::new(1.0, 3.0)];
+        let t = Transform::with_rotate(-PI / 4.0);
+        let b = transform_points_bounds(&pts, t);
+        let s = 2.0f64.sqrt();
+        assert_approx_eq!(b.x_min(), 0.0);
+        assert_approx_eq!(b.x_max(), 4.0 * s);
+        assert_approx_eq!(b.y_min(), 0.0);
+        assert_approx_eq!(b.y_max(), s);
+        let loose = t.transform_bbox(BBox::new(pts));
+        assert!(b.bounded_by(loose));
+        assert!(loose.y_max() - loose.y_min() > b.y_max() - b.y_min() + 1.0);
+        assert_eq!(transform_points_bounds(&[], t), BBox::default());
+    }
+
+    #[test]
+    fn test_apply() {
+        let t = Transform::with_translate(1.0, 2.0).scale(3.0, -1.0);
+        let pts = [Pt::new(0.0, 0.0), Pt::new(1.5, -2.0), Pt::new(4.0, 8.0)];
+        let out: Vec<_> = t.apply(pts).collect();
+        assert_eq!(out, [t * pts[0], t * pts[1], t * pts[2]]);
+        assert_eq!(t.apply(pts).nth(1), Some(Pt::new(7.5, 0.0)));
+    }
+
+    #[test]
+    fn test_compose_all() {
+        let t0 = Transform::with_translate(3.0, 5.0);
+        let t1 = Transform::with_scale(7.0, 11.0);
+        let t2 = Transform::with_rotate(std::f32::consts::PI / 2.0);
+        assert_eq!(Transform::compose_all(&[t0, t1, t2]), t0 * t1 * t2);
+        assert_eq!(Transform::compose_all(&[t2, t0]), t2 * t0);
+        assert_eq!(Transform::compose_all(&[t1]), t1);
+        assert_eq!(Transform::<f32>::compose_all(&[]), Transform::default());
+    }
+
     #[test]
     fn test_transform() {
         assert_eq!(